@@ -0,0 +1,154 @@
+use std::any::Any;
+
+mod rng;
+
+pub use rng::Lcg;
+
+/// Every day's crate exposes a unit struct implementing this so the `aoc` runner can call any part of any day
+/// without somebody un-commenting a println in a main().
+///
+/// `parse` turns the raw puzzle input into whatever both parts start from and the parts only ever see that, so
+/// `aoc bench` can time parsing and each part on their own
+pub trait Solution {
+    type Parsed: 'static;
+
+    /// false when there's nothing to run for part 2, e.g. day 25 only has the one puzzle
+    const HAS_PART_2: bool = true;
+
+    fn parse(&self, input: &str) -> Self::Parsed;
+
+    fn part1(&self, parsed: &Self::Parsed) -> String;
+
+    /// Only gets called when HAS_PART_2 is set
+    fn part2(&self, _parsed: &Self::Parsed) -> String {
+        unreachable!("Expected part 2 to only run for days that have one")
+    }
+}
+
+/// A Solution with its parsed input boxed up, so the runner can keep every day behind the same `&dyn`
+pub trait DynSolution {
+    fn parse(&self, input: &str) -> Box<dyn Any>;
+
+    /// `parsed` has to come from this day's parse
+    fn part1(&self, parsed: &dyn Any) -> String;
+
+    fn has_part2(&self) -> bool;
+
+    /// None when the day has no part 2
+    fn part2(&self, parsed: &dyn Any) -> Option<String>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn parse(&self, input: &str) -> Box<dyn Any> {
+        Box::new(Solution::parse(self, input))
+    }
+
+    fn part1(&self, parsed: &dyn Any) -> String {
+        Solution::part1(self, downcast::<S>(parsed))
+    }
+
+    fn has_part2(&self) -> bool {
+        S::HAS_PART_2
+    }
+
+    fn part2(&self, parsed: &dyn Any) -> Option<String> {
+        S::HAS_PART_2.then(|| Solution::part2(self, downcast::<S>(parsed)))
+    }
+}
+
+fn downcast<S: Solution>(parsed: &dyn Any) -> &S::Parsed {
+    parsed.downcast_ref()
+        .expect("Expected the parsed input to come from the same day's parse")
+}
+
+/// Splits a puzzle input on its blank lines, e.g. day 5's rules and page orders.
+/// Works line by line so CRLF files and extra blank lines between sections don't throw it off
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut section_start = None;
+    let mut section_end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+
+        if content.trim().is_empty() {
+            if let Some(start) = section_start.take() {
+                sections.push(&input[start..section_end]);
+            }
+        } else {
+            section_start.get_or_insert(offset);
+            section_end = offset + content.len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(start) = section_start {
+        sections.push(&input[start..section_end]);
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Doubler;
+
+    impl Solution for Doubler {
+        type Parsed = Vec<u32>;
+
+        const HAS_PART_2: bool = false;
+
+        fn parse(&self, input: &str) -> Vec<u32> {
+            input.split(',').map(|number| number.parse().unwrap()).collect()
+        }
+
+        fn part1(&self, numbers: &Vec<u32>) -> String {
+            numbers.iter().map(|number| number * 2).sum::<u32>().to_string()
+        }
+    }
+
+    struct Counter;
+
+    impl Solution for Counter {
+        type Parsed = usize;
+
+        fn parse(&self, input: &str) -> usize {
+            input.len()
+        }
+
+        fn part1(&self, length: &usize) -> String {
+            length.to_string()
+        }
+    }
+
+    #[test]
+    fn parsed_input_goes_through_the_box() {
+        let solution: &dyn DynSolution = &Doubler;
+        let parsed = solution.parse("1,2,3");
+
+        assert_eq!(solution.part1(parsed.as_ref()), "12");
+        assert!(!solution.has_part2());
+        assert_eq!(solution.part2(parsed.as_ref()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "same day's parse")]
+    fn parsed_input_from_another_day() {
+        let parsed = DynSolution::parse(&Counter, "abc");
+        DynSolution::part1(&Doubler, parsed.as_ref());
+    }
+
+    #[test]
+    fn splits_on_blank_lines() {
+        assert_eq!(sections("a\nb\n\nc\n"), vec!["a\nb", "c"]);
+    }
+
+    #[test]
+    fn crlf_and_extra_blank_lines() {
+        assert_eq!(sections("a\r\nb\r\n\r\n\r\n  \r\nc\r\n"), vec!["a\r\nb", "c"]);
+    }
+}
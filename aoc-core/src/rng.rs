@@ -0,0 +1,63 @@
+/// A tiny seeded linear congruential generator, for fuzz checks and random test cases that have to come out the same
+/// every run. Nowhere near good enough for anything that matters, but it saves pulling in a crate
+#[derive(Debug, Clone)]
+pub struct Lcg(u64);
+
+impl Lcg {
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// 31 random bits. The low bits of an LCG go round in short cycles so only the top ones are handed out
+    pub fn draw(&mut self) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.0 >> 33
+    }
+
+    /// Something from 0 up to but not including `bound`
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.draw() % bound
+    }
+
+    /// `count` random bits, up to 64, pieced together from as many draws as it takes
+    pub fn bits(&mut self, count: u32) -> u64 {
+        let mut value = 0;
+        let mut filled = 0;
+        while filled < count {
+            value = (value << 31) | self.draw();
+            filled += 31;
+        }
+
+        if count >= 64 { value } else { value & ((1 << count) - 1) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_seed_same_numbers() {
+        let mut first = Lcg::new(7);
+        let mut second = Lcg::new(7);
+
+        for _ in 0..100 {
+            assert_eq!(first.draw(), second.draw());
+        }
+        assert_ne!(Lcg::new(7).draw(), Lcg::new(8).draw());
+    }
+
+    #[test]
+    fn stays_in_range() {
+        let mut rng = Lcg::new(1);
+
+        for _ in 0..1000 {
+            assert!(rng.draw() < 1 << 31);
+            assert!(rng.below(6) < 6);
+            assert!(rng.bits(45) < 1 << 45);
+        }
+        assert_eq!(rng.bits(0), 0);
+        // with 64 bits something has to land in the top one sooner or later
+        assert!((0..100).any(|_| rng.bits(64) >> 63 == 1));
+    }
+}
@@ -0,0 +1,109 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction {
+    /// Clockwise from Up, the ones you can walk in on most days
+    pub const ORTHOGONAL: [Direction; 4] = [Direction::Up, Direction::Right, Direction::Down, Direction::Left];
+
+    /// Clockwise from Up, diagonals included
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::UpRight,
+        Direction::Right,
+        Direction::DownRight,
+        Direction::Down,
+        Direction::DownLeft,
+        Direction::Left,
+        Direction::UpLeft,
+    ];
+
+    /// (row, col) change for one step
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::Up => (-1, 0),
+            Direction::UpRight => (-1, 1),
+            Direction::Right => (0, 1),
+            Direction::DownRight => (1, 1),
+            Direction::Down => (1, 0),
+            Direction::DownLeft => (1, -1),
+            Direction::Left => (0, -1),
+            Direction::UpLeft => (-1, -1),
+        }
+    }
+
+    /// 90 degrees to the right
+    pub fn rotate_clockwise(&self) -> Direction {
+        self.turn(2)
+    }
+
+    /// 90 degrees to the left
+    pub fn rotate_counterclockwise(&self) -> Direction {
+        self.turn(6)
+    }
+
+    pub fn opposite(&self) -> Direction {
+        self.turn(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        let (row_offset, col_offset) = self.offset();
+        row_offset != 0 && col_offset != 0
+    }
+
+    /// The arrows moves are written in, e.g. day 15's robot
+    pub fn from_arrow(arrow: char) -> Option<Direction> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    // eighths of a turn clockwise
+    fn turn(&self, eighths: usize) -> Direction {
+        let index = Direction::ALL.iter().position(|direction| direction == self).unwrap();
+        Direction::ALL[(index + eighths) % 8]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rotation() {
+        assert_eq!(Direction::Up.rotate_clockwise(), Direction::Right);
+        assert_eq!(Direction::Left.rotate_clockwise(), Direction::Up);
+        assert_eq!(Direction::Up.rotate_counterclockwise(), Direction::Left);
+        assert_eq!(Direction::UpRight.rotate_clockwise(), Direction::DownRight);
+        assert_eq!(Direction::DownLeft.opposite(), Direction::UpRight);
+
+        for direction in Direction::ALL {
+            assert_eq!(direction.rotate_clockwise().rotate_counterclockwise(), direction);
+        }
+    }
+
+    #[test]
+    fn diagonals() {
+        assert_eq!(Direction::ALL.iter().filter(|direction| direction.is_diagonal()).count(), 4);
+        assert!(Direction::ORTHOGONAL.iter().all(|direction| !direction.is_diagonal()));
+    }
+
+    #[test]
+    fn arrows() {
+        assert_eq!("^>v<".chars().map(Direction::from_arrow).collect::<Vec<_>>(),
+                   vec![Some(Direction::Up), Some(Direction::Right), Some(Direction::Down), Some(Direction::Left)]);
+        assert_eq!(Direction::from_arrow('x'), None);
+    }
+}
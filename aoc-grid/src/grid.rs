@@ -0,0 +1,248 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::Pos;
+
+/// A dense, rectangular grid stored row by row
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Every line of the puzzle text is a row, each char goes through `parse_cell`.
+    /// Panics when the rows aren't all the same width, the puzzle inputs always are
+    pub fn parse_with(input: &str, mut parse_cell: impl FnMut(char) -> T) -> Grid<T> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in input.lines() {
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+
+            let row_width = line.chars().count();
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    panic!("Expected every grid row to be {} wide, row {} is {}", width, height, row_width)
+                }
+                Some(_) => {}
+            }
+
+            cells.extend(line.chars().map(&mut parse_cell));
+            height += 1;
+        }
+
+        Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Pos) -> bool {
+        pos.row >= 0 && pos.col >= 0 && (pos.row as usize) < self.height && (pos.col as usize) < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index_of(pos).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index_of(pos).map(|index| &mut self.cells[index])
+    }
+
+    /// Panics off the grid, same as indexing
+    pub fn set(&mut self, pos: Pos, value: T) {
+        self[pos] = value;
+    }
+
+    /// Every position, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| Pos::new((index / width) as i32, (index % width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The in-bounds positions next to `pos`, no diagonals
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours4().filter(|neighbour| self.in_bounds(*neighbour))
+    }
+
+    /// The in-bounds positions around `pos`, diagonals included
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8().filter(|neighbour| self.in_bounds(*neighbour))
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Same shape, every cell run through `convert`
+    pub fn map<U>(&self, convert: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(convert).collect(),
+        }
+    }
+
+    fn index_of(&self, pos: Pos) -> Option<usize> {
+        self.in_bounds(pos).then(|| pos.row as usize * self.width + pos.col as usize)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T: PartialEq> Grid<T> {
+    /// First position holding `value`, row by row
+    pub fn find(&self, value: &T) -> Option<Pos> {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Pos> + 'a {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(pos, _)| pos)
+    }
+
+    /// Finds a marker like S, E, @ or ^ and swaps in whatever's really under it, usually floor
+    pub fn take_marker(&mut self, marker: &T, replacement: T) -> Option<Pos> {
+        let pos = self.find(marker)?;
+        self[pos] = replacement;
+        Some(pos)
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Grid<char> {
+        Grid::parse_with(input, |cell| cell)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        let index = self.index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is off the {}x{} grid", pos, self.width, self.height));
+        &self.cells[index]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        let index = self.index_of(pos)
+            .unwrap_or_else(|| panic!("{:?} is off the {}x{} grid", pos, width, height));
+        &mut self.cells[index]
+    }
+}
+
+/// Prints it back out the way the puzzle draws it
+impl fmt::Display for Grid<char> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows() {
+            writeln!(f, "{}", row.iter().collect::<String>())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MAZE: &str = "\
+#####
+#S.E#
+#.#.#
+#####
+";
+
+    #[test]
+    fn parse_and_index() {
+        let grid = Grid::parse(MAZE);
+
+        assert_eq!((grid.width(), grid.height()), (5, 4));
+        assert_eq!(grid[Pos::new(1, 1)], 'S');
+        assert_eq!(grid.get(Pos::new(2, 2)), Some(&'#'));
+        assert_eq!(grid.get(Pos::new(4, 0)), None);
+        assert_eq!(grid.get(Pos::new(0, -1)), None);
+    }
+
+    #[test]
+    fn crlf_input() {
+        assert_eq!(Grid::parse(&MAZE.replace('\n', "\r\n")), Grid::parse(MAZE));
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected every grid row to be 3 wide")]
+    fn ragged_rows() {
+        Grid::parse("...\n..\n");
+    }
+
+    #[test]
+    fn markers() {
+        let mut grid = Grid::parse(MAZE);
+
+        assert_eq!(grid.find(&'E'), Some(Pos::new(1, 3)));
+        assert_eq!(grid.take_marker(&'S', '.'), Some(Pos::new(1, 1)));
+        assert_eq!(grid[Pos::new(1, 1)], '.');
+        assert_eq!(grid.find(&'S'), None);
+        assert_eq!(grid.find_all(&'.').count(), 4);
+    }
+
+    #[test]
+    fn neighbours_stay_on_the_grid() {
+        let grid = Grid::parse(MAZE);
+
+        assert_eq!(grid.neighbours4(Pos::new(0, 0)).collect::<Vec<_>>(), vec![Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbours8(Pos::new(0, 0)).count(), 3);
+        assert_eq!(grid.neighbours8(Pos::new(1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let mut grid = Grid::parse(MAZE);
+        grid.set(Pos::new(2, 1), 'O');
+
+        assert_eq!(grid.to_string(), MAZE.replacen("#.#", "#O#", 1));
+    }
+
+    #[test]
+    fn parse_with_and_map() {
+        let heights = Grid::parse_with("012\n345\n", |cell| cell.to_digit(10).unwrap());
+
+        assert_eq!(heights[Pos::new(1, 2)], 5);
+        assert_eq!(heights.map(|height| height * 2)[Pos::new(1, 2)], 10);
+        assert_eq!(Grid::new(3, 2, 0).positions().last(), Some(Pos::new(1, 2)));
+    }
+}
@@ -0,0 +1,10 @@
+//! The char grids half the puzzles are drawn on. Rows go down and columns go right, so `Direction::Up` is row - 1
+//! and the top left corner is (0, 0)
+
+mod direction;
+mod grid;
+mod pos;
+
+pub use direction::Direction;
+pub use grid::Grid;
+pub use pos::Pos;
@@ -0,0 +1,91 @@
+use std::ops::{Add, Sub};
+
+use crate::Direction;
+
+/// Signed so stepping off the edge of a grid is just a position `Grid::get` says no to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pos {
+    pub row: i32,
+    pub col: i32,
+}
+
+impl Pos {
+    pub const fn new(row: i32, col: i32) -> Self {
+        Pos { row, col }
+    }
+
+    pub fn next(&self, direction: Direction) -> Pos {
+        self.step(direction, 1)
+    }
+
+    /// `steps` moves in `direction` at once
+    pub fn step(&self, direction: Direction, steps: i32) -> Pos {
+        let (row_offset, col_offset) = direction.offset();
+        Pos::new(self.row + row_offset * steps, self.col + col_offset * steps)
+    }
+
+    pub fn neighbours4(&self) -> impl Iterator<Item = Pos> {
+        let pos = *self;
+        Direction::ORTHOGONAL.into_iter().map(move |direction| pos.next(direction))
+    }
+
+    pub fn neighbours8(&self) -> impl Iterator<Item = Pos> {
+        let pos = *self;
+        Direction::ALL.into_iter().map(move |direction| pos.next(direction))
+    }
+
+    pub fn manhattan(&self, other: &Pos) -> i32 {
+        (self.row - other.row).abs() + (self.col - other.col).abs()
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, other: Pos) -> Pos {
+        Pos::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+/// The offset that gets you from `other` to `self`
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, other: Pos) -> Pos {
+        Pos::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping() {
+        let pos = Pos::new(2, 3);
+
+        assert_eq!(pos.next(Direction::Up), Pos::new(1, 3));
+        assert_eq!(pos.next(Direction::DownLeft), Pos::new(3, 2));
+        assert_eq!(pos.step(Direction::Right, 4), Pos::new(2, 7));
+    }
+
+    #[test]
+    fn neighbourhoods() {
+        let pos = Pos::new(0, 0);
+
+        assert_eq!(pos.neighbours4().collect::<Vec<_>>(),
+                   vec![Pos::new(-1, 0), Pos::new(0, 1), Pos::new(1, 0), Pos::new(0, -1)]);
+        assert_eq!(pos.neighbours8().count(), 8);
+        assert!(pos.neighbours8().all(|neighbour| neighbour != pos));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Pos::new(1, 8);
+        let b = Pos::new(2, 5);
+
+        assert_eq!(a - b, Pos::new(-1, 3));
+        assert_eq!(b + (a - b), a);
+        assert_eq!(a.manhattan(&b), 4);
+    }
+}
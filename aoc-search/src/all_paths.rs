@@ -0,0 +1,180 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+use std::hash::Hash;
+
+use crate::nodes::Nodes;
+use crate::Cost;
+
+/// Every path that ties for cheapest from `start` to a goal. Goals that cost more than the cheapest one are never
+/// expanded, so the search stops once everything left in the queue is already too expensive
+pub fn all_shortest_paths<N, C, I>(start: N,
+                                   mut neighbours: impl FnMut(&N) -> I,
+                                   mut is_goal: impl FnMut(&N) -> bool) -> Option<ShortestPaths<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut costs = Vec::new();
+    let mut predecessors: Vec<Vec<usize>> = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    let mut best_cost = None;
+    let mut goals = Vec::new();
+
+    let (start_index, _) = nodes.insert(start);
+    costs.push(C::ZERO);
+    predecessors.push(Vec::new());
+    queue.push(Reverse((C::ZERO, start_index)));
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        if best_cost.is_some_and(|best| cost > best) {
+            break;
+        }
+
+        let node = nodes.get(index).clone();
+        if is_goal(&node) {
+            best_cost = Some(cost);
+            goals.push(index);
+            continue;
+        }
+
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            let (next_index, is_new) = nodes.insert(next);
+
+            if is_new {
+                costs.push(next_cost);
+                predecessors.push(vec![index]);
+            } else if next_cost < costs[next_index] {
+                // everything that got here before was more expensive, this is the cheapest way in now
+                costs[next_index] = next_cost;
+                predecessors[next_index] = vec![index];
+            } else {
+                if next_cost == costs[next_index] {
+                    predecessors[next_index].push(index);
+                }
+                continue;
+            }
+
+            queue.push(Reverse((next_cost, next_index)));
+        }
+    }
+
+    best_cost.map(|cost| ShortestPaths {
+        nodes,
+        predecessors,
+        goals,
+        cost,
+    })
+}
+
+/// What `all_shortest_paths` found, every cheapest path is still in here but only gets walked out on request
+pub struct ShortestPaths<N, C> {
+    nodes: Nodes<N>,
+    predecessors: Vec<Vec<usize>>,
+    goals: Vec<usize>,
+    cost: C,
+}
+
+impl<N: Clone + Eq + Hash, C: Cost> ShortestPaths<N, C> {
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    /// Every goal reached at the cheapest cost
+    pub fn goals(&self) -> impl Iterator<Item = &N> {
+        self.goals.iter().map(|&index| self.nodes.get(index))
+    }
+
+    /// Every node that's on at least one of the cheapest paths
+    pub fn nodes(&self) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        let mut stack = self.goals.clone();
+
+        while let Some(index) = stack.pop() {
+            if seen.insert(index) {
+                stack.extend(&self.predecessors[index]);
+            }
+        }
+
+        seen.into_iter().map(|index| self.nodes.get(index).clone()).collect()
+    }
+
+    /// Every cheapest path written out start to goal. There can be exponentially many, `nodes` is the one to use
+    /// when only the tiles matter
+    pub fn paths(&self) -> Vec<Vec<N>> {
+        let mut paths = Vec::new();
+        for &goal in &self.goals {
+            self.walk_back(goal, &mut vec![goal], &mut paths);
+        }
+        paths
+    }
+
+    fn walk_back(&self, index: usize, reversed_path: &mut Vec<usize>, paths: &mut Vec<Vec<N>>) {
+        if self.predecessors[index].is_empty() {
+            paths.push(reversed_path.iter().rev().map(|&index| self.nodes.get(index).clone()).collect());
+            return;
+        }
+
+        for &previous in &self.predecessors[index] {
+            reversed_path.push(previous);
+            self.walk_back(previous, reversed_path, paths);
+            reversed_path.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // two equally cheap ways from a to d, through b or c, and a third through e that costs more
+    fn diamond(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 1), ('e', 1)],
+            'b' | 'c' => vec![('d', 1)],
+            'e' => vec![('d', 5)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn every_tied_path() {
+        let found = all_shortest_paths('a', diamond, |&node| node == 'd').unwrap();
+
+        assert_eq!(found.cost(), 2);
+        assert_eq!(found.goals().collect::<Vec<_>>(), vec![&'d']);
+
+        let mut paths = found.paths();
+        paths.sort();
+        assert_eq!(paths, vec![vec!['a', 'b', 'd'], vec!['a', 'c', 'd']]);
+
+        assert_eq!(found.nodes(), HashSet::from(['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn several_goals() {
+        let found = all_shortest_paths('a', diamond, |&node| node == 'b' || node == 'c').unwrap();
+
+        assert_eq!(found.cost(), 1);
+        assert_eq!(found.paths().len(), 2);
+    }
+
+    #[test]
+    fn start_is_the_goal() {
+        let found = all_shortest_paths('a', diamond, |&node| node == 'a').unwrap();
+
+        assert_eq!(found.cost(), 0);
+        assert_eq!(found.paths(), vec![vec!['a']]);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert!(all_shortest_paths('d', diamond, |&node| node == 'a').is_none());
+    }
+}
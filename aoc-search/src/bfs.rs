@@ -0,0 +1,114 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::nodes::Nodes;
+
+/// Fewest steps from `start` to the first node `is_goal` accepts. The path includes both ends, so the number of
+/// steps is one less than its length
+pub fn bfs<N, I>(start: N,
+                 mut neighbours: impl FnMut(&N) -> I,
+                 mut is_goal: impl FnMut(&N) -> bool) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new();
+    let mut parents = Vec::new();
+    let mut queue = VecDeque::new();
+
+    let (start_index, _) = nodes.insert(start);
+    parents.push(start_index);
+    queue.push_back(start_index);
+
+    while let Some(index) = queue.pop_front() {
+        let node = nodes.get(index).clone();
+        if is_goal(&node) {
+            return Some(nodes.path_to(&parents, index));
+        }
+
+        for next in neighbours(&node) {
+            let (next_index, is_new) = nodes.insert(next);
+            if is_new {
+                parents.push(index);
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    None
+}
+
+/// Steps from `start` to everything reachable from it
+pub fn bfs_distances<N, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new();
+    let mut distances = Vec::new();
+    let mut queue = VecDeque::new();
+
+    let (start_index, _) = nodes.insert(start);
+    distances.push(0);
+    queue.push_back(start_index);
+
+    while let Some(index) = queue.pop_front() {
+        let node = nodes.get(index).clone();
+        let distance = distances[index];
+
+        for next in neighbours(&node) {
+            let (next_index, is_new) = nodes.insert(next);
+            if is_new {
+                distances.push(distance + 1);
+                queue.push_back(next_index);
+            }
+        }
+    }
+
+    nodes.into_map(distances)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 - 1 - 2 - 3 with a shortcut 0 - 3, and 4 on its own
+    fn line_with_shortcut(node: &u32) -> Vec<u32> {
+        match node {
+            0 => vec![1, 3],
+            1 => vec![0, 2],
+            2 => vec![1, 3],
+            3 => vec![2, 0],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        assert_eq!(bfs(0, line_with_shortcut, |&node| node == 2), Some(vec![0, 1, 2]));
+        assert_eq!(bfs(0, line_with_shortcut, |&node| node == 3), Some(vec![0, 3]));
+        assert_eq!(bfs(0, line_with_shortcut, |&node| node == 0), Some(vec![0]));
+        assert_eq!(bfs(0, line_with_shortcut, |&node| node == 4), None);
+    }
+
+    #[test]
+    fn stops_at_the_goal() {
+        let mut expanded = Vec::new();
+        bfs(0u32, |&node| {
+            expanded.push(node);
+            vec![node + 1]
+        }, |&node| node == 5);
+
+        assert_eq!(expanded, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn distances() {
+        let distances = bfs_distances(0, line_with_shortcut);
+
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&2], 2);
+        assert_eq!(distances[&3], 1);
+        assert!(!distances.contains_key(&4));
+    }
+}
@@ -0,0 +1,16 @@
+use std::ops::Add;
+
+/// Anything the weighted searches can add up and compare
+pub trait Cost: Copy + Ord + Add<Output = Self> {
+    const ZERO: Self;
+}
+
+macro_rules! impl_cost {
+    ($($int:ty),*) => {
+        $(impl Cost for $int {
+            const ZERO: Self = 0;
+        })*
+    };
+}
+
+impl_cost!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,156 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::hash::Hash;
+
+use crate::nodes::Nodes;
+use crate::Cost;
+
+/// Cheapest path from `start` to the first node `is_goal` accepts, along with what it cost. `neighbours` gives each
+/// node reachable in one step and the cost of that step
+pub fn dijkstra<N, C, I>(start: N,
+                         neighbours: impl FnMut(&N) -> I,
+                         is_goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(start, neighbours, |_| C::ZERO, is_goal)
+}
+
+/// Dijkstra steered by `heuristic`, which has to never overestimate the cost left to a goal (manhattan distance on
+/// a grid, say) or the path it finds might not be the cheapest
+pub fn astar<N, C, I>(start: N,
+                      mut neighbours: impl FnMut(&N) -> I,
+                      mut heuristic: impl FnMut(&N) -> C,
+                      mut is_goal: impl FnMut(&N) -> bool) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut costs = Vec::new();
+    let mut parents = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    let estimate = heuristic(&start);
+    let (start_index, _) = nodes.insert(start);
+    costs.push(C::ZERO);
+    parents.push(start_index);
+    queue.push(Reverse((estimate, C::ZERO, start_index)));
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // a cheaper way here was found after this one was queued
+        if cost > costs[index] {
+            continue;
+        }
+
+        let node = nodes.get(index).clone();
+        if is_goal(&node) {
+            return Some((nodes.path_to(&parents, index), cost));
+        }
+
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            let (next_index, is_new) = nodes.insert(next);
+
+            if is_new {
+                costs.push(next_cost);
+                parents.push(index);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+                parents[next_index] = index;
+            } else {
+                continue;
+            }
+
+            queue.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+
+    None
+}
+
+/// Cheapest cost from `start` to everything reachable from it
+pub fn dijkstra_distances<N, C, I>(start: N, mut neighbours: impl FnMut(&N) -> I) -> HashMap<N, C>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut nodes = Nodes::new();
+    let mut costs = Vec::new();
+    let mut queue = BinaryHeap::new();
+
+    let (start_index, _) = nodes.insert(start);
+    costs.push(C::ZERO);
+    queue.push(Reverse((C::ZERO, start_index)));
+
+    while let Some(Reverse((cost, index))) = queue.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+
+        let node = nodes.get(index).clone();
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            let (next_index, is_new) = nodes.insert(next);
+
+            if is_new {
+                costs.push(next_cost);
+            } else if next_cost < costs[next_index] {
+                costs[next_index] = next_cost;
+            } else {
+                continue;
+            }
+
+            queue.push(Reverse((next_cost, next_index)));
+        }
+    }
+
+    nodes.into_map(costs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // going straight from a to c costs more than going round through b
+    fn triangle(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('c', 5)],
+            'b' => vec![('c', 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn cheapest_beats_fewest_steps() {
+        assert_eq!(dijkstra('a', triangle, |&node| node == 'c'), Some((vec!['a', 'b', 'c'], 3)));
+        assert_eq!(dijkstra('c', triangle, |&node| node == 'a'), None);
+    }
+
+    #[test]
+    fn distances() {
+        let distances = dijkstra_distances('a', triangle);
+
+        assert_eq!(distances[&'a'], 0);
+        assert_eq!(distances[&'b'], 1);
+        assert_eq!(distances[&'c'], 3);
+    }
+
+    #[test]
+    fn astar_on_a_line() {
+        // walking a number line to 10, the heuristic keeps it from wandering off into the negatives
+        let mut expanded = 0;
+        let result = astar(0i32, |&node| {
+            expanded += 1;
+            [(node - 1, 1), (node + 1, 1)]
+        }, |&node| (10 - node).abs(), |&node| node == 10);
+
+        assert_eq!(result.map(|(path, cost)| (path.len(), cost)), Some((11, 10)));
+        assert_eq!(expanded, 10);
+    }
+}
@@ -0,0 +1,14 @@
+//! Graph searches that don't care what a node is. A day describes its state space with a `neighbours` closure (plus
+//! a step cost for the weighted ones) and an `is_goal` closure, and the search stops as soon as it's answered
+//! the question instead of exploring the whole space
+
+mod all_paths;
+mod bfs;
+mod cost;
+mod dijkstra;
+mod nodes;
+
+pub use all_paths::{all_shortest_paths, ShortestPaths};
+pub use bfs::{bfs, bfs_distances};
+pub use cost::Cost;
+pub use dijkstra::{astar, dijkstra, dijkstra_distances};
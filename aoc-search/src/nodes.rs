@@ -0,0 +1,52 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Hands out a stable index per node so the searches can keep costs and parents in plain Vecs, and so nodes never
+/// need to be `Ord` to sit in a heap
+pub(crate) struct Nodes<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    pub(crate) fn new() -> Self {
+        Nodes {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+        }
+    }
+
+    /// The node's index and whether this is the first time it's been seen
+    pub(crate) fn insert(&mut self, node: N) -> (usize, bool) {
+        if let Some(&index) = self.indices.get(&node) {
+            return (index, false);
+        }
+
+        let index = self.nodes.len();
+        self.indices.insert(node.clone(), index);
+        self.nodes.push(node);
+        (index, true)
+    }
+
+    pub(crate) fn get(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    /// Follows `parents` back from `end` until it hits the node that's its own parent, the start
+    pub(crate) fn path_to(&self, parents: &[usize], end: usize) -> Vec<N> {
+        let mut path = vec![self.nodes[end].clone()];
+        let mut current = end;
+
+        while parents[current] != current {
+            current = parents[current];
+            path.push(self.nodes[current].clone());
+        }
+
+        path.reverse();
+        path
+    }
+
+    pub(crate) fn into_map<T>(self, values: Vec<T>) -> HashMap<N, T> {
+        self.nodes.into_iter().zip(values).collect()
+    }
+}
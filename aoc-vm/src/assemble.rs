@@ -0,0 +1,169 @@
+use std::collections::HashMap;
+
+use crate::{Combo, Instruction};
+
+/// Turns mnemonic source into the numbers the machine runs. One instruction per line, `#` starts a comment, and a
+/// `name:` in front of a line (or on a line of its own) labels the next instruction so `jnz name` can jump to it
+///
+/// ```text
+/// start:
+///     adv 3
+///     out A   # the digit that's left
+///     jnz start
+/// ```
+///
+/// Errors say which line is wrong and why
+pub fn assemble(source: &str) -> Result<Vec<usize>, String> {
+    let mut labels = HashMap::new();
+    let mut statements = Vec::new();
+
+    // labels can be jumped to before they're defined, so they all get an address before anything is encoded
+    for (index, line) in source.lines().enumerate() {
+        let line_number = index + 1;
+        let mut statement = line.split('#').next().unwrap_or("").trim();
+
+        if let Some((label, rest)) = statement.split_once(':') {
+            let label = label.trim();
+            if !is_label(label) {
+                return Err(format!("line {}: '{}' isn't a label, they start with a letter", line_number, label));
+            }
+            if labels.insert(label, statements.len() * 2).is_some() {
+                return Err(format!("line {}: label '{}' is already defined", line_number, label));
+            }
+            statement = rest.trim();
+        }
+
+        if !statement.is_empty() {
+            statements.push((line_number, statement));
+        }
+    }
+
+    let mut program = Vec::new();
+    for (line_number, statement) in statements {
+        let instruction = parse_instruction(statement, &labels)
+            .map_err(|message| format!("line {}: {}", line_number, message))?;
+        program.extend(instruction.encode());
+    }
+
+    Ok(program)
+}
+
+fn parse_instruction(statement: &str, labels: &HashMap<&str, usize>) -> Result<Instruction, String> {
+    let words: Vec<&str> = statement.split_whitespace().collect();
+    let (mnemonic, operand) = match words[..] {
+        [mnemonic] => (mnemonic.to_ascii_lowercase(), None),
+        [mnemonic, operand] => (mnemonic.to_ascii_lowercase(), Some(operand)),
+        _ => return Err(format!("expected a mnemonic and at most one operand, got '{}'", statement)),
+    };
+
+    let needs_operand = || operand.ok_or_else(|| format!("{} needs an operand", mnemonic));
+
+    Ok(match mnemonic.as_str() {
+        "adv" => Instruction::Adv(parse_combo(needs_operand()?)?),
+        "bxl" => Instruction::Bxl(parse_literal(needs_operand()?)?),
+        "bst" => Instruction::Bst(parse_combo(needs_operand()?)?),
+        "jnz" => Instruction::Jnz(parse_jump_target(needs_operand()?, labels)?),
+        // the operand is ignored, so it's optional
+        "bxc" => Instruction::Bxc(operand.map(parse_literal).transpose()?.unwrap_or(0)),
+        "out" => Instruction::Out(parse_combo(needs_operand()?)?),
+        "bdv" => Instruction::Bdv(parse_combo(needs_operand()?)?),
+        "cdv" => Instruction::Cdv(parse_combo(needs_operand()?)?),
+        _ => return Err(format!("unknown mnemonic '{}'", mnemonic)),
+    })
+}
+
+/// 0-3 or a register, the numbers 4-6 mean registers too but spelling them out is less confusing
+fn parse_combo(operand: &str) -> Result<Combo, String> {
+    match operand {
+        "A" | "a" => return Ok(Combo::A),
+        "B" | "b" => return Ok(Combo::B),
+        "C" | "c" => return Ok(Combo::C),
+        _ => {}
+    }
+
+    // 4, 5 and 6 are A, B and C the same as in the machine code
+    Combo::decode(parse_literal(operand)?).ok_or_else(|| String::from("combo operand 7 is reserved"))
+}
+
+fn parse_literal(operand: &str) -> Result<usize, String> {
+    match operand.parse::<usize>() {
+        Ok(value) if value < 8 => Ok(value),
+        Ok(value) => Err(format!("operands are 3 bits, {} doesn't fit", value)),
+        Err(_) => Err(format!("expected a number from 0 to 7, got '{}'", operand)),
+    }
+}
+
+/// jnz only has 3 bits for where it's going, so a label further in than 7 can't be reached
+fn parse_jump_target(operand: &str, labels: &HashMap<&str, usize>) -> Result<usize, String> {
+    if !is_label(operand) {
+        return parse_literal(operand);
+    }
+
+    match labels.get(operand) {
+        Some(&address) if address < 8 => Ok(address),
+        Some(&address) => Err(format!("label '{}' is at {}, jnz can only reach 0 to 7", operand, address)),
+        None => Err(format!("no label called '{}'", operand)),
+    }
+}
+
+fn is_label(word: &str) -> bool {
+    let mut chars = word.chars();
+    chars.next().is_some_and(|first| first.is_ascii_alphabetic() || first == '_') &&
+        chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assembly;
+
+    #[test]
+    fn assembles_with_labels() {
+        let source = "\
+# the part 2 example
+start:
+    adv 3
+    out A   # the digit that's left
+    jnz start
+";
+        assert_eq!(assemble(source), Ok(vec![0, 3, 5, 4, 3, 0]));
+        assert_eq!(assemble("skip: jnz end\nout 1\nend: bxc"), Ok(vec![3, 4, 5, 1, 4, 0]));
+    }
+
+    #[test]
+    fn round_trips_through_disassembly() {
+        let programs: [&[usize]; 4] = [
+            &[0, 3, 5, 4, 3, 0],
+            &[2, 4, 1, 5, 7, 5, 1, 6, 4, 2, 5, 5, 0, 3, 3, 0],
+            // jumping into the middle of an instruction
+            &[0, 1, 3, 3, 5, 4],
+            &[4, 6, 6, 0, 7, 6, 1, 7],
+        ];
+
+        for program in programs {
+            assert_eq!(assemble(&assembly(program).unwrap()).as_deref(), Ok(program));
+        }
+    }
+
+    #[test]
+    fn operand_ranges() {
+        assert_eq!(assemble("adv 7"), Err(String::from("line 1: combo operand 7 is reserved")));
+        assert_eq!(assemble("out 5"), assemble("out B"));
+        assert_eq!(assemble("adv 4\nbst 6"), Ok(vec![0, 4, 2, 6]));
+        assert_eq!(assemble("bxl 8"), Err(String::from("line 1: operands are 3 bits, 8 doesn't fit")));
+        assert_eq!(assemble("bxl A"), Err(String::from("line 1: expected a number from 0 to 7, got 'A'")));
+        assert_eq!(assemble("\nout"), Err(String::from("line 2: out needs an operand")));
+    }
+
+    #[test]
+    fn bad_source() {
+        assert_eq!(assemble("mul 3"), Err(String::from("line 1: unknown mnemonic 'mul'")));
+        assert_eq!(assemble("adv 1 2"), Err(String::from("line 1: expected a mnemonic and at most one operand, got 'adv 1 2'")));
+        assert_eq!(assemble("jnz nowhere"), Err(String::from("line 1: no label called 'nowhere'")));
+        assert_eq!(assemble("a: out 1\na: out 2"), Err(String::from("line 2: label 'a' is already defined")));
+        assert_eq!(assemble("1x: out 1"), Err(String::from("line 1: '1x' isn't a label, they start with a letter")));
+
+        let too_far = "out 1\nout 1\nout 1\nout 1\nfar: out 1\njnz far";
+        assert_eq!(assemble(too_far), Err(String::from("line 6: label 'far' is at 8, jnz can only reach 0 to 7")));
+    }
+}
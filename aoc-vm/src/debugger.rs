@@ -0,0 +1,296 @@
+use std::collections::BTreeSet;
+use std::io::{self, BufRead, Write};
+
+use crate::{Machine, Step};
+
+const HELP: &str = "\
+step [n]      run n instructions, 1 by default
+continue      run until a breakpoint or the program halts
+break [ip]    stop before the instruction at ip, on its own lists the breakpoints
+delete <ip>   remove a breakpoint
+regs          show the registers
+set <A|B|C> <n>
+out           show everything output so far
+quit";
+
+/// Wraps a machine with breakpoints and keeps hold of its output, one command at a time
+pub struct Debugger {
+    machine: Machine,
+    breakpoints: BTreeSet<usize>,
+    output: Vec<usize>,
+}
+
+impl Debugger {
+    pub fn new(machine: Machine) -> Self {
+        Debugger {
+            machine,
+            breakpoints: BTreeSet::new(),
+            output: Vec::new(),
+        }
+    }
+
+    pub fn machine(&self) -> &Machine {
+        &self.machine
+    }
+
+    pub fn output(&self) -> &[usize] {
+        &self.output
+    }
+
+    /// Runs one command line and returns what it has to say. None means the session is over
+    pub fn execute(&mut self, line: &str) -> Option<Result<String, String>> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+
+        let result = match words[..] {
+            ["step" | "s"] => self.step(1),
+            ["step" | "s", count] => parse_number(count).and_then(|count| self.step(count)),
+            ["continue" | "c"] => self.continue_running(),
+            ["break" | "b"] => Ok(self.list_breakpoints()),
+            ["break" | "b", ip] => parse_number(ip).and_then(|ip| self.add_breakpoint(ip)),
+            ["delete" | "d", ip] => parse_number(ip).and_then(|ip| self.delete_breakpoint(ip)),
+            ["regs" | "r"] => Ok(self.registers()),
+            ["set", register, value] => parse_number(value).and_then(|value| self.set(register, value)),
+            ["out" | "o"] => Ok(self.output_so_far()),
+            ["help" | "h"] => Ok(String::from(HELP)),
+            ["quit" | "q"] => return None,
+            [] => Ok(String::new()),
+            _ => Err(format!("unknown command '{}', try help", line.trim())),
+        };
+
+        Some(result)
+    }
+
+    /// Reads commands from `input` until it runs out or says quit. Interactively each command gets a prompt, a
+    /// script has each command echoed instead so the transcript reads the same either way
+    pub fn run_session(&mut self, input: impl BufRead, mut output: impl Write, interactive: bool) -> io::Result<()> {
+        writeln!(output, "{}", self.status())?;
+
+        let mut lines = input.lines();
+        loop {
+            if interactive {
+                write!(output, "> ")?;
+                output.flush()?;
+            }
+
+            let Some(line) = lines.next() else {
+                return Ok(());
+            };
+            let line = line?;
+
+            // scripts can have comments and blank lines to keep them readable
+            let command = line.split('#').next().unwrap_or("").trim();
+            if !interactive {
+                if command.is_empty() {
+                    continue;
+                }
+                writeln!(output, "> {}", command)?;
+            }
+
+            match self.execute(command) {
+                None => return Ok(()),
+                Some(Ok(response)) if response.is_empty() => {}
+                Some(Ok(response)) => writeln!(output, "{}", response)?,
+                Some(Err(message)) => writeln!(output, "error: {}", message)?,
+            }
+        }
+    }
+
+    /// Runs a whole script and hands back the transcript
+    pub fn run_script(&mut self, script: &str) -> String {
+        let mut transcript = Vec::new();
+        self.run_session(script.as_bytes(), &mut transcript, false)
+            .expect("writing to a Vec can't fail");
+
+        String::from_utf8(transcript).expect("the transcript is all ASCII")
+    }
+
+    /// Where the machine is, the instruction it'll run next and the registers
+    fn status(&self) -> String {
+        let next = match self.machine.current_instruction() {
+            None => String::from("halted"),
+            Some(Ok(instruction)) => instruction.to_string(),
+            Some(Err(err)) => format!("error: {}", err),
+        };
+
+        format!("ip {:>3}  {:<6}  {}", self.machine.ip(), next, self.registers())
+    }
+
+    fn registers(&self) -> String {
+        let registers = self.machine.registers();
+        format!("A={} B={} C={}", registers.a, registers.b, registers.c)
+    }
+
+    fn step(&mut self, count: usize) -> Result<String, String> {
+        let mut lines = Vec::new();
+
+        for _ in 0..count {
+            if self.machine.is_halted() {
+                break;
+            }
+            if let Some(value) = self.step_once()? {
+                lines.push(format!("OUT {}", value));
+            }
+            lines.push(self.status());
+        }
+
+        if lines.is_empty() {
+            lines.push(self.status());
+        }
+
+        Ok(lines.join("\n"))
+    }
+
+    fn continue_running(&mut self) -> Result<String, String> {
+        let mut lines = Vec::new();
+
+        // always get off the current instruction, otherwise sitting on a breakpoint means never moving
+        let mut first = true;
+        while !self.machine.is_halted() && (first || !self.breakpoints.contains(&self.machine.ip())) {
+            first = false;
+            if let Some(value) = self.step_once()? {
+                lines.push(format!("OUT {}", value));
+            }
+        }
+
+        if !self.machine.is_halted() {
+            lines.push(format!("breakpoint at {}", self.machine.ip()));
+        }
+        lines.push(self.status());
+
+        Ok(lines.join("\n"))
+    }
+
+    fn step_once(&mut self) -> Result<Option<usize>, String> {
+        match self.machine.step().map_err(|err| err.to_string())? {
+            Step::Output(value) => {
+                self.output.push(value);
+                Ok(Some(value))
+            }
+            Step::Ran(_) | Step::Halted => Ok(None),
+        }
+    }
+
+    fn list_breakpoints(&self) -> String {
+        if self.breakpoints.is_empty() {
+            return String::from("no breakpoints");
+        }
+
+        let ips: Vec<String> = self.breakpoints.iter().map(|ip| ip.to_string()).collect();
+        format!("breakpoints at {}", ips.join(", "))
+    }
+
+    fn add_breakpoint(&mut self, ip: usize) -> Result<String, String> {
+        if !ip.is_multiple_of(2) || ip >= self.machine.program().len() {
+            return Err(format!("{} isn't the start of an instruction", ip));
+        }
+
+        self.breakpoints.insert(ip);
+        Ok(format!("breakpoint at {}", ip))
+    }
+
+    fn delete_breakpoint(&mut self, ip: usize) -> Result<String, String> {
+        if self.breakpoints.remove(&ip) {
+            Ok(format!("removed breakpoint at {}", ip))
+        } else {
+            Err(format!("no breakpoint at {}", ip))
+        }
+    }
+
+    fn set(&mut self, register: &str, value: usize) -> Result<String, String> {
+        let registers = self.machine.registers_mut();
+        match register {
+            "A" | "a" => registers.a = value,
+            "B" | "b" => registers.b = value,
+            "C" | "c" => registers.c = value,
+            _ => return Err(format!("there's no register {}, only A, B and C", register)),
+        }
+
+        Ok(self.registers())
+    }
+
+    fn output_so_far(&self) -> String {
+        self.output.iter()
+            .map(|value| value.to_string())
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+fn parse_number(value: &str) -> Result<usize, String> {
+    value.parse::<usize>()
+        .map_err(|_| format!("expected a number, got '{}'", value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Registers;
+
+    fn part_2_example() -> Debugger {
+        Debugger::new(Machine::new(vec![0, 3, 5, 4, 3, 0], Registers::new(2024, 0, 0)))
+    }
+
+    #[test]
+    fn replays_a_script() {
+        let mut debugger = part_2_example();
+
+        let transcript = debugger.run_script("\
+# get through the first pass
+step 3
+break 2
+continue
+regs
+set A 0
+continue
+out
+");
+
+        assert_eq!(transcript, "\
+ip   0  adv 3   A=2024 B=0 C=0
+> step 3
+ip   2  out A   A=253 B=0 C=0
+OUT 5
+ip   4  jnz 0   A=253 B=0 C=0
+ip   0  adv 3   A=253 B=0 C=0
+> break 2
+breakpoint at 2
+> continue
+breakpoint at 2
+ip   2  out A   A=31 B=0 C=0
+> regs
+A=31 B=0 C=0
+> set A 0
+A=0 B=0 C=0
+> continue
+OUT 0
+ip   6  halted  A=0 B=0 C=0
+> out
+5,0
+");
+    }
+
+    #[test]
+    fn bad_commands_dont_end_the_session() {
+        let mut debugger = part_2_example();
+
+        assert_eq!(debugger.execute("break 3"), Some(Err(String::from("3 isn't the start of an instruction"))));
+        assert_eq!(debugger.execute("set D 1"), Some(Err(String::from("there's no register D, only A, B and C"))));
+        assert_eq!(debugger.execute("jump"), Some(Err(String::from("unknown command 'jump', try help"))));
+        assert_eq!(debugger.execute("quit"), None);
+    }
+
+    #[test]
+    fn quit_stops_a_script() {
+        let mut debugger = part_2_example();
+
+        debugger.run_script("step\nquit\nstep\n");
+        assert_eq!(debugger.machine().ip(), 2);
+    }
+
+    #[test]
+    fn vm_errors_are_reported() {
+        let mut debugger = Debugger::new(Machine::new(vec![5, 7], Registers::default()));
+
+        assert_eq!(debugger.execute("step"), Some(Err(String::from("invalid operand 7 at 1"))));
+    }
+}
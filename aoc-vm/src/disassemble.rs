@@ -0,0 +1,235 @@
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::{Combo, Instruction, VmError};
+
+/// Decodes the whole program two numbers at a time
+pub fn decode(program: &[usize]) -> Result<Vec<Instruction>, VmError> {
+    program.chunks(2)
+        .enumerate()
+        .map(|(index, pair)| match pair {
+            [opcode, operand] => Instruction::decode(index * 2, *opcode, *operand),
+            _ => Err(VmError::MissingOperand { ip: index * 2 }),
+        })
+        .collect()
+}
+
+/// One instruction per line with its address, mnemonic and what it does. Jump targets get a label so the loop is
+/// easy to spot
+///
+/// ```text
+/// L0:
+///    0  bst A   B = A % 8
+///    2  bxl 3   B = B ^ 3
+///   ..
+///   14  jnz 0   if A != 0 goto L0
+/// ```
+pub fn disassemble(program: &[usize]) -> Result<String, VmError> {
+    let instructions = decode(program)?;
+    let targets = jump_targets(&instructions);
+
+    let mut listing = String::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        let ip = index * 2;
+        if targets.contains(&ip) {
+            writeln!(listing, "L{}:", ip).unwrap();
+        }
+        writeln!(listing, "{:>4}  {:<6}  {}", ip, instruction.to_string(), effect(instruction, &targets)).unwrap();
+    }
+
+    Ok(listing)
+}
+
+/// The program as source `assemble` reads back, with a label on everything that gets jumped to
+pub fn assembly(program: &[usize]) -> Result<String, VmError> {
+    let instructions = decode(program)?;
+    let targets = jump_targets(&instructions);
+
+    let mut source = String::new();
+    for (index, instruction) in instructions.iter().enumerate() {
+        let ip = index * 2;
+        if targets.contains(&ip) {
+            writeln!(source, "L{}:", ip).unwrap();
+        }
+
+        match instruction {
+            Instruction::Jnz(target) if targets.contains(target) => writeln!(source, "    jnz L{}", target),
+            _ => writeln!(source, "    {}", instruction),
+        }.unwrap();
+    }
+
+    Ok(source)
+}
+
+/// The program as pseudo-code, with backwards `jnz`s turned into `do { } while A != 0` loops. Every puzzle program
+/// is one of those loops, outputting a digit and shifting A each time round, which is what part 2 leans on
+pub fn decompile(program: &[usize]) -> Result<String, VmError> {
+    let instructions = decode(program)?;
+    let targets = jump_targets(&instructions);
+    let loops = nested_loops(&instructions);
+
+    let mut code = String::new();
+    let mut depth = 0;
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        let ip = index * 2;
+
+        if let Some(&(start, end)) = loops.iter().find(|(start, _)| *start == ip) {
+            if let Some(summary) = summarise_loop(&instructions[start / 2..=end / 2]) {
+                writeln!(code, "{}// {}", indent(depth), summary).unwrap();
+            }
+            writeln!(code, "{}do {{", indent(depth)).unwrap();
+            depth += 1;
+        }
+
+        if loops.iter().any(|&(_, end)| end == ip) {
+            depth -= 1;
+            writeln!(code, "{}}} while A != 0", indent(depth)).unwrap();
+        } else {
+            if targets.contains(&ip) && !loops.iter().any(|&(start, _)| start == ip) {
+                writeln!(code, "L{}:", ip).unwrap();
+            }
+            writeln!(code, "{}{}", indent(depth), effect(instruction, &targets)).unwrap();
+        }
+    }
+
+    Ok(code)
+}
+
+fn effect(instruction: &Instruction, targets: &BTreeSet<usize>) -> String {
+    match instruction {
+        Instruction::Adv(combo) => format!("A = A >> {}", combo),
+        Instruction::Bxl(literal) => format!("B = B ^ {}", literal),
+        Instruction::Bst(combo) => format!("B = {} % 8", combo),
+        Instruction::Jnz(target) if targets.contains(target) => format!("if A != 0 goto L{}", target),
+        // odd targets land on an operand, and anything past the end just halts
+        Instruction::Jnz(target) => format!("if A != 0 goto {}", target),
+        Instruction::Bxc(_) => String::from("B = B ^ C"),
+        Instruction::Out(combo) => format!("output({} % 8)", combo),
+        Instruction::Bdv(combo) => format!("B = A >> {}", combo),
+        Instruction::Cdv(combo) => format!("C = A >> {}", combo),
+    }
+}
+
+/// Addresses some `jnz` can land on that are actually the start of an instruction
+fn jump_targets(instructions: &[Instruction]) -> BTreeSet<usize> {
+    instructions.iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Jnz(target) if target % 2 == 0 && target / 2 < instructions.len() => Some(*target),
+            _ => None,
+        })
+        .collect()
+}
+
+/// (start, end) of each backwards `jnz` that can be written as a loop, ends being the address of the `jnz`.
+/// Loops that cross each other instead of nesting, or that start where another already does, are left as gotos
+fn nested_loops(instructions: &[Instruction]) -> Vec<(usize, usize)> {
+    let mut loops: Vec<(usize, usize)> = Vec::new();
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        let end = index * 2;
+        let Instruction::Jnz(start) = *instruction else {
+            continue;
+        };
+        if start % 2 != 0 || start > end {
+            continue;
+        }
+
+        let crosses = loops.iter().any(|&(other_start, other_end)| other_start < start && start <= other_end);
+        let shares_start = loops.iter().any(|&(other_start, _)| other_start == start);
+        if !crosses && !shares_start {
+            loops.push((start, end));
+        }
+    }
+
+    loops
+}
+
+/// Spells out the digit-at-a-time shape when the loop has it
+fn summarise_loop(body: &[Instruction]) -> Option<String> {
+    let outputs = body.iter().filter(|instruction| matches!(instruction, Instruction::Out(_))).count();
+    let shifts: Vec<Combo> = body.iter()
+        .filter_map(|instruction| match instruction {
+            Instruction::Adv(combo) => Some(*combo),
+            _ => None,
+        })
+        .collect();
+
+    match (outputs, &shifts[..]) {
+        (1, [Combo::Literal(bits)]) => Some(format!("outputs one digit per pass, then drops the low {} bits of A", bits)),
+        _ => None,
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "    ".repeat(depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the part 2 example, it outputs a copy of itself
+    const QUINE: [usize; 6] = [0, 3, 5, 4, 3, 0];
+
+    #[test]
+    fn listing() {
+        assert_eq!(disassemble(&QUINE).unwrap(), "\
+L0:
+   0  adv 3   A = A >> 3
+   2  out A   output(A % 8)
+   4  jnz 0   if A != 0 goto L0
+");
+    }
+
+    #[test]
+    fn assembly_source() {
+        assert_eq!(assembly(&QUINE).unwrap(), "\
+L0:
+    adv 3
+    out A
+    jnz L0
+");
+    }
+
+    #[test]
+    fn pseudo_code() {
+        assert_eq!(decompile(&QUINE).unwrap(), "\
+// outputs one digit per pass, then drops the low 3 bits of A
+do {
+    A = A >> 3
+    output(A % 8)
+} while A != 0
+");
+    }
+
+    #[test]
+    fn straight_line_and_odd_jumps() {
+        assert_eq!(decompile(&[2, 4, 4, 1, 3, 3]).unwrap(), "\
+B = A % 8
+B = B ^ C
+if A != 0 goto 3
+");
+    }
+
+    #[test]
+    fn nested_loops_render_nested() {
+        // an inner loop at 2..=4 inside the outer 0..=8
+        let program = [0, 1, 0, 1, 3, 2, 5, 4, 3, 0];
+        assert_eq!(decompile(&program).unwrap(), "\
+do {
+    A = A >> 1
+    do {
+        A = A >> 1
+    } while A != 0
+    output(A % 8)
+} while A != 0
+");
+    }
+
+    #[test]
+    fn bad_programs() {
+        assert_eq!(disassemble(&[0, 7]), Err(VmError::InvalidOperand { ip: 0, operand: 7 }));
+        assert_eq!(disassemble(&[0, 3, 5]), Err(VmError::MissingOperand { ip: 2 }));
+    }
+}
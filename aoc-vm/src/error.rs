@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// Everything that stops a program other than running off the end of it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VmError {
+    InvalidOpcode { ip: usize, opcode: usize },
+    /// Combo operand 7 is reserved, and nothing above 7 fits in 3 bits
+    InvalidOperand { ip: usize, operand: usize },
+    /// The last opcode in the program has nothing after it to read
+    MissingOperand { ip: usize },
+    BudgetExhausted { steps: usize },
+}
+
+impl fmt::Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VmError::InvalidOpcode { ip, opcode } => write!(f, "invalid opcode {} at {}", opcode, ip),
+            VmError::InvalidOperand { ip, operand } => write!(f, "invalid operand {} at {}", operand, ip + 1),
+            VmError::MissingOperand { ip } => write!(f, "opcode at {} has no operand after it", ip),
+            VmError::BudgetExhausted { steps } => write!(f, "still running after {} instructions", steps),
+        }
+    }
+}
+
+impl std::error::Error for VmError {}
@@ -0,0 +1,162 @@
+use std::fmt;
+
+use crate::VmError;
+
+/// Combo operands 0-3 are just those numbers, 4-6 read a register
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combo {
+    Literal(usize),
+    A,
+    B,
+    C,
+}
+
+impl Combo {
+    /// None for the reserved 7 and anything that isn't 3 bits
+    pub fn decode(operand: usize) -> Option<Combo> {
+        match operand {
+            0..=3 => Some(Combo::Literal(operand)),
+            4 => Some(Combo::A),
+            5 => Some(Combo::B),
+            6 => Some(Combo::C),
+            _ => None,
+        }
+    }
+
+    pub fn encode(&self) -> usize {
+        match self {
+            Combo::Literal(value) => *value,
+            Combo::A => 4,
+            Combo::B => 5,
+            Combo::C => 6,
+        }
+    }
+}
+
+/// One opcode/operand pair. Literal operands are kept as plain numbers, combo operands are decoded
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    /// opcode 0, A = A >> combo
+    Adv(Combo),
+    /// opcode 1, B = B ^ literal
+    Bxl(usize),
+    /// opcode 2, B = combo % 8
+    Bst(Combo),
+    /// opcode 3, jump to the literal unless A is 0
+    Jnz(usize),
+    /// opcode 4, B = B ^ C. The operand is read and ignored, it's only kept so the program encodes back the same
+    Bxc(usize),
+    /// opcode 5, outputs combo % 8
+    Out(Combo),
+    /// opcode 6, B = A >> combo
+    Bdv(Combo),
+    /// opcode 7, C = A >> combo
+    Cdv(Combo),
+}
+
+impl Instruction {
+    /// `ip` is where the opcode sits, it's only used to say where things went wrong
+    pub fn decode(ip: usize, opcode: usize, operand: usize) -> Result<Instruction, VmError> {
+        let combo = || Combo::decode(operand).ok_or(VmError::InvalidOperand { ip, operand });
+        let literal = || if operand < 8 {
+            Ok(operand)
+        } else {
+            Err(VmError::InvalidOperand { ip, operand })
+        };
+
+        Ok(match opcode {
+            0 => Instruction::Adv(combo()?),
+            1 => Instruction::Bxl(literal()?),
+            2 => Instruction::Bst(combo()?),
+            3 => Instruction::Jnz(literal()?),
+            4 => Instruction::Bxc(literal()?),
+            5 => Instruction::Out(combo()?),
+            6 => Instruction::Bdv(combo()?),
+            7 => Instruction::Cdv(combo()?),
+            _ => return Err(VmError::InvalidOpcode { ip, opcode }),
+        })
+    }
+
+    pub fn opcode(&self) -> usize {
+        match self {
+            Instruction::Adv(_) => 0,
+            Instruction::Bxl(_) => 1,
+            Instruction::Bst(_) => 2,
+            Instruction::Jnz(_) => 3,
+            Instruction::Bxc(_) => 4,
+            Instruction::Out(_) => 5,
+            Instruction::Bdv(_) => 6,
+            Instruction::Cdv(_) => 7,
+        }
+    }
+
+    pub fn operand(&self) -> usize {
+        match self {
+            Instruction::Adv(combo) | Instruction::Bst(combo) | Instruction::Out(combo) |
+            Instruction::Bdv(combo) | Instruction::Cdv(combo) => combo.encode(),
+            Instruction::Bxl(literal) | Instruction::Jnz(literal) | Instruction::Bxc(literal) => *literal,
+        }
+    }
+
+    pub fn encode(&self) -> [usize; 2] {
+        [self.opcode(), self.operand()]
+    }
+}
+
+/// Registers by letter, literals as the number
+impl fmt::Display for Combo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Combo::Literal(value) => write!(f, "{}", value),
+            Combo::A => write!(f, "A"),
+            Combo::B => write!(f, "B"),
+            Combo::C => write!(f, "C"),
+        }
+    }
+}
+
+/// The mnemonic and operand, `bst A` or `jnz 0`
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Adv(combo) => write!(f, "adv {}", combo),
+            Instruction::Bxl(literal) => write!(f, "bxl {}", literal),
+            Instruction::Bst(combo) => write!(f, "bst {}", combo),
+            Instruction::Jnz(literal) => write!(f, "jnz {}", literal),
+            Instruction::Bxc(literal) => write!(f, "bxc {}", literal),
+            Instruction::Out(combo) => write!(f, "out {}", combo),
+            Instruction::Bdv(combo) => write!(f, "bdv {}", combo),
+            Instruction::Cdv(combo) => write!(f, "cdv {}", combo),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips() {
+        for opcode in 0..8 {
+            for operand in 0..7 {
+                let instruction = Instruction::decode(0, opcode, operand).unwrap();
+                assert_eq!(instruction.encode(), [opcode, operand]);
+            }
+        }
+    }
+
+    #[test]
+    fn combo_seven_is_reserved() {
+        assert_eq!(Instruction::decode(4, 0, 7), Err(VmError::InvalidOperand { ip: 4, operand: 7 }));
+        assert_eq!(Instruction::decode(4, 5, 7), Err(VmError::InvalidOperand { ip: 4, operand: 7 }));
+        // literal operands can be 7
+        assert_eq!(Instruction::decode(4, 1, 7), Ok(Instruction::Bxl(7)));
+        assert_eq!(Instruction::decode(4, 3, 7), Ok(Instruction::Jnz(7)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(Instruction::decode(2, 8, 0), Err(VmError::InvalidOpcode { ip: 2, opcode: 8 }));
+        assert_eq!(Instruction::decode(2, 1, 8), Err(VmError::InvalidOperand { ip: 2, operand: 8 }));
+    }
+}
@@ -0,0 +1,18 @@
+//! Day 17's 3-bit computer. Three registers, eight instructions, and a program that's a list of 3-bit numbers
+//! read in opcode/operand pairs
+
+mod assemble;
+mod debugger;
+mod disassemble;
+mod error;
+mod instruction;
+mod machine;
+mod search;
+
+pub use assemble::assemble;
+pub use debugger::Debugger;
+pub use disassemble::{assembly, decode, decompile, disassemble};
+pub use error::VmError;
+pub use instruction::{Combo, Instruction};
+pub use machine::{Machine, Registers, Step};
+pub use search::{find_a_for_output, find_quine, SearchError};
@@ -0,0 +1,251 @@
+use std::ops::ControlFlow;
+
+use crate::{Combo, Instruction, VmError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    pub a: usize,
+    pub b: usize,
+    pub c: usize,
+}
+
+impl Registers {
+    pub fn new(a: usize, b: usize, c: usize) -> Self {
+        Registers { a, b, c }
+    }
+
+    pub fn combo(&self, combo: Combo) -> usize {
+        match combo {
+            Combo::Literal(value) => value,
+            Combo::A => self.a,
+            Combo::B => self.b,
+            Combo::C => self.c,
+        }
+    }
+}
+
+/// What a single `Machine::step` did
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Ran(Instruction),
+    Output(usize),
+    /// The instruction pointer is past the end of the program, stepping again does nothing
+    Halted,
+}
+
+/// Runs a program one instruction at a time. Without a budget a program that never halts runs forever
+#[derive(Debug, Clone)]
+pub struct Machine {
+    program: Vec<usize>,
+    registers: Registers,
+    ip: usize,
+    steps: usize,
+    budget: Option<usize>,
+}
+
+impl Machine {
+    pub fn new(program: Vec<usize>, registers: Registers) -> Self {
+        Machine {
+            program,
+            registers,
+            ip: 0,
+            steps: 0,
+            budget: None,
+        }
+    }
+
+    /// Gives up with `VmError::BudgetExhausted` instead of running more than `budget` instructions
+    pub fn with_budget(mut self, budget: usize) -> Self {
+        self.budget = Some(budget);
+        self
+    }
+
+    pub fn program(&self) -> &[usize] {
+        &self.program
+    }
+
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    pub fn registers_mut(&mut self) -> &mut Registers {
+        &mut self.registers
+    }
+
+    pub fn ip(&self) -> usize {
+        self.ip
+    }
+
+    /// Instructions run so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.ip >= self.program.len()
+    }
+
+    /// The instruction `step` will run next, None once the program has halted
+    pub fn current_instruction(&self) -> Option<Result<Instruction, VmError>> {
+        if self.is_halted() {
+            return None;
+        }
+
+        let opcode = self.program[self.ip];
+        let Some(&operand) = self.program.get(self.ip + 1) else {
+            return Some(Err(VmError::MissingOperand { ip: self.ip }));
+        };
+
+        Some(Instruction::decode(self.ip, opcode, operand))
+    }
+
+    pub fn step(&mut self) -> Result<Step, VmError> {
+        let Some(instruction) = self.current_instruction() else {
+            return Ok(Step::Halted);
+        };
+        let instruction = instruction?;
+
+        if self.budget.is_some_and(|budget| self.steps >= budget) {
+            return Err(VmError::BudgetExhausted { steps: self.steps });
+        }
+        self.steps += 1;
+
+        let registers = &mut self.registers;
+        let mut next_ip = self.ip + 2;
+        let mut output = None;
+
+        match instruction {
+            Instruction::Adv(combo) => registers.a = shift(registers.a, registers.combo(combo)),
+            Instruction::Bxl(literal) => registers.b ^= literal,
+            // bitwise & works like modulo for powers of 8
+            Instruction::Bst(combo) => registers.b = registers.combo(combo) & 7,
+            Instruction::Jnz(target) => {
+                if registers.a != 0 {
+                    next_ip = target;
+                }
+            }
+            Instruction::Bxc(_) => registers.b ^= registers.c,
+            Instruction::Out(combo) => output = Some(registers.combo(combo) & 7),
+            Instruction::Bdv(combo) => registers.b = shift(registers.a, registers.combo(combo)),
+            Instruction::Cdv(combo) => registers.c = shift(registers.a, registers.combo(combo)),
+        }
+
+        self.ip = next_ip;
+
+        Ok(match output {
+            Some(value) => Step::Output(value),
+            None => Step::Ran(instruction),
+        })
+    }
+
+    /// Runs until the program halts and returns everything it output
+    pub fn run(&mut self) -> Result<Vec<usize>, VmError> {
+        let mut output = Vec::new();
+        self.run_with(|value| {
+            output.push(value);
+            ControlFlow::Continue(())
+        })?;
+
+        Ok(output)
+    }
+
+    /// Runs until the program halts or `on_output` breaks, whichever comes first. Breaking leaves the machine
+    /// just after the `out` so it can be picked back up
+    pub fn run_with(&mut self, mut on_output: impl FnMut(usize) -> ControlFlow<()>) -> Result<(), VmError> {
+        loop {
+            match self.step()? {
+                Step::Halted => return Ok(()),
+                Step::Output(value) => {
+                    if on_output(value).is_break() {
+                        return Ok(());
+                    }
+                }
+                Step::Ran(_) => {}
+            }
+        }
+    }
+}
+
+/// Dividing by 2^amount, shifting every bit out is 0 rather than an overflow
+fn shift(value: usize, amount: usize) -> usize {
+    u32::try_from(amount).ok()
+        .and_then(|amount| value.checked_shr(amount))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(registers: Registers, program: &[usize]) -> (Registers, Vec<usize>) {
+        let mut machine = Machine::new(program.to_vec(), registers);
+        let output = machine.run().unwrap();
+        (*machine.registers(), output)
+    }
+
+    // the small examples from the instruction descriptions
+    #[test]
+    fn instruction_examples() {
+        assert_eq!(run(Registers::new(0, 0, 9), &[2, 6]).0.b, 1);
+        assert_eq!(run(Registers::new(10, 0, 0), &[5, 0, 5, 1, 5, 4]).1, vec![0, 1, 2]);
+
+        let (registers, output) = run(Registers::new(2024, 0, 0), &[0, 1, 5, 4, 3, 0]);
+        assert_eq!(output, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(registers.a, 0);
+
+        assert_eq!(run(Registers::new(0, 29, 0), &[1, 7]).0.b, 26);
+        assert_eq!(run(Registers::new(0, 2024, 43690), &[4, 0]).0.b, 44354);
+    }
+
+    #[test]
+    fn stepping() {
+        let mut machine = Machine::new(vec![0, 1, 5, 4, 3, 0], Registers::new(2, 0, 0));
+
+        assert_eq!(machine.current_instruction(), Some(Ok(Instruction::Adv(Combo::Literal(1)))));
+        assert_eq!(machine.step(), Ok(Step::Ran(Instruction::Adv(Combo::Literal(1)))));
+        assert_eq!(machine.step(), Ok(Step::Output(1)));
+        assert_eq!(machine.step(), Ok(Step::Ran(Instruction::Jnz(0))));
+        assert_eq!(machine.ip(), 0);
+
+        machine.run().unwrap();
+        assert!(machine.is_halted());
+        assert_eq!(machine.step(), Ok(Step::Halted));
+        assert_eq!(machine.steps(), 6);
+    }
+
+    #[test]
+    fn errors_instead_of_panics() {
+        let registers = Registers::default();
+
+        assert_eq!(Machine::new(vec![8, 0], registers).run(), Err(VmError::InvalidOpcode { ip: 0, opcode: 8 }));
+        assert_eq!(Machine::new(vec![5, 7], registers).run(), Err(VmError::InvalidOperand { ip: 0, operand: 7 }));
+        assert_eq!(Machine::new(vec![5, 0, 5], registers).run(), Err(VmError::MissingOperand { ip: 2 }));
+    }
+
+    #[test]
+    fn budget() {
+        // A never reaches 0 so this jumps back to itself forever
+        let mut machine = Machine::new(vec![3, 0], Registers::new(1, 0, 0)).with_budget(100);
+
+        assert_eq!(machine.run(), Err(VmError::BudgetExhausted { steps: 100 }));
+    }
+
+    #[test]
+    fn huge_shifts_are_zero() {
+        assert_eq!(run(Registers::new(usize::MAX, 200, 0), &[0, 5]).0.a, 0);
+    }
+
+    #[test]
+    fn output_callback_can_stop() {
+        let mut machine = Machine::new(vec![5, 0, 5, 1, 5, 2], Registers::default());
+        let mut seen = Vec::new();
+
+        machine.run_with(|value| {
+            seen.push(value);
+            if value == 1 { ControlFlow::Break(()) } else { ControlFlow::Continue(()) }
+        }).unwrap();
+
+        assert_eq!(seen, vec![0, 1]);
+        assert_eq!(machine.run().unwrap(), vec![2]);
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::ops::ControlFlow;
+
+use crate::{Machine, Registers, VmError};
+
+/// Way more than any puzzle program needs to print its output, it's only there so a candidate A that loops forever
+/// counts as a miss instead of hanging the search
+const CANDIDATE_BUDGET: usize = 100_000;
+
+/// Why `find_quine` came back empty handed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchError {
+    /// Every branch was tried and none of them print the target
+    NoSolution,
+    /// A would need more bits than a usize has
+    Overflow,
+    /// The program itself is broken, no A will fix that
+    Vm(VmError),
+}
+
+impl fmt::Display for SearchError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchError::NoSolution => write!(f, "no value of A makes the program print the target"),
+            SearchError::Overflow => write!(f, "A needs more than {} bits", usize::BITS),
+            SearchError::Vm(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for SearchError {}
+
+/// Smallest A that makes `program` print itself
+pub fn find_quine(program: &[usize]) -> Result<usize, SearchError> {
+    find_a_for_output(program, program)
+}
+
+/// Smallest A that makes `program` print exactly `target`, with B and C starting at 0.
+///
+/// Leans on the shape every puzzle program has: each pass prints one digit that only depends on A, then drops the
+/// low 3 bits of A. So the last digit printed only depends on A's top 3 bits, the one before it on the top 6 and so
+/// on. Working back from the last digit, each of the 8 ways to extend A by 3 bits that still prints the right tail
+/// gets explored, and a branch that runs dry backtracks to the next candidate. Trying candidates smallest first means
+/// the first A that prints all of `target` is the smallest one
+pub fn find_a_for_output(program: &[usize], target: &[usize]) -> Result<usize, SearchError> {
+    let a = extend(program, target, 0, 0)?.ok_or(SearchError::NoSolution)?;
+
+    // a program without the puzzle's shape can match every tail and still not print the target as a whole
+    if prints(program, a, target)? {
+        Ok(a)
+    } else {
+        Err(SearchError::NoSolution)
+    }
+}
+
+/// `a` already prints the last `matched` digits of `target`, tries each way of tacking 3 more bits on
+fn extend(program: &[usize], target: &[usize], a: usize, matched: usize) -> Result<Option<usize>, SearchError> {
+    if matched == target.len() {
+        return Ok(Some(a));
+    }
+
+    let shifted = a.checked_mul(8).ok_or(SearchError::Overflow)?;
+    let tail = &target[target.len() - matched - 1..];
+
+    for bits in 0..8 {
+        let candidate = shifted + bits;
+        if prints(program, candidate, tail)? {
+            if let Some(found) = extend(program, target, candidate, matched + 1)? {
+                return Ok(Some(found));
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+fn prints(program: &[usize], a: usize, expected: &[usize]) -> Result<bool, SearchError> {
+    let mut machine = Machine::new(program.to_vec(), Registers::new(a, 0, 0)).with_budget(CANDIDATE_BUDGET);
+    let mut output = Vec::new();
+
+    let ran = machine.run_with(|value| {
+        output.push(value);
+        // no point running on once it's printed something it shouldn't have
+        if expected.starts_with(&output) {
+            ControlFlow::Continue(())
+        } else {
+            ControlFlow::Break(())
+        }
+    });
+
+    match ran {
+        Ok(()) => Ok(output == expected),
+        Err(VmError::BudgetExhausted { .. }) => Ok(false),
+        Err(err) => Err(SearchError::Vm(err)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(find_quine(&[0, 3, 5, 4, 3, 0]), Ok(117440));
+    }
+
+    // shaped like a real puzzle input, C reads bits above the current 3 so plenty of candidates match a digit and
+    // then lead nowhere
+    const BRANCHY: [usize; 16] = [2, 4, 1, 5, 7, 5, 1, 6, 4, 2, 5, 5, 0, 3, 3, 0];
+
+    #[test]
+    fn backtracks_to_the_smallest_a() {
+        // brute force every A that prints 4 digits, the search has to agree on the smallest one for each output
+        let mut smallest = HashMap::new();
+        for a in 8_usize.pow(3)..8_usize.pow(4) {
+            let output = Machine::new(BRANCHY.to_vec(), Registers::new(a, 0, 0)).run().unwrap();
+            smallest.entry(output).or_insert(a);
+        }
+
+        for (output, a) in smallest {
+            assert_eq!(find_a_for_output(&BRANCHY, &output), Ok(a), "output {:?}", output);
+        }
+    }
+
+    #[test]
+    fn nothing_prints_it() {
+        // outputs A's low 3 bits without ever changing A, so it's 0 or it never stops
+        assert_eq!(find_a_for_output(&[5, 4, 3, 0], &[1, 2]), Err(SearchError::NoSolution));
+        assert_eq!(find_quine(&[0, 3, 5, 4, 3, 0, 5, 1]), Err(SearchError::NoSolution));
+    }
+
+    #[test]
+    fn broken_program() {
+        assert_eq!(find_quine(&[0, 7, 3, 0]), Err(SearchError::Vm(VmError::InvalidOperand { ip: 0, operand: 7 })));
+    }
+}
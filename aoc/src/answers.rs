@@ -0,0 +1,195 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+/// Answers we've submitted, one file per day as dayNN.txt. Looks like
+///
+/// # lines starting with # are ignored
+/// part1: 170068701
+/// part2 too high: 123486709
+/// part2 too low: 74207640
+/// part2 wrong: 90669331
+///
+/// A plain `partN:` line is the accepted answer, the rest are guesses the site rejected
+pub const ANSWERS_DIR: &str = "answers";
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(format!("day{:02}.txt", day))
+}
+
+#[derive(Debug, Default)]
+pub struct Answers {
+    parts: [PartAnswers; 2],
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct PartAnswers {
+    pub correct: Option<String>,
+    pub too_high: Vec<String>,
+    pub too_low: Vec<String>,
+    pub wrong: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Correct,
+    /// we had the right answer at some point and now get something else
+    Regression { expected: String },
+    /// no accepted answer yet, but this one was already rejected or is out of bounds. Holds the reason
+    KnownWrong(String),
+    /// nothing recorded that says yes or no
+    Unverified,
+}
+
+impl Answers {
+    /// A day without an answers file just has nothing recorded
+    pub fn load(day: u8) -> Result<Answers, String> {
+        let path = default_path(day);
+
+        match fs::read_to_string(&path) {
+            Ok(contents) => Answers::parse(&contents)
+                .map_err(|err| format!("{}: {}", path.display(), err)),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(format!("couldn't read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers, String> {
+        let mut answers = Answers::default();
+
+        for (line_index, line) in contents.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let line_number = line_index + 1;
+            let (key, value) = line.split_once(':')
+                .ok_or_else(|| format!("line {}: expected 'partN: answer', got '{}'", line_number, line))?;
+            let value = value.trim().to_string();
+
+            let (part, kind) = match key.trim().split_once(char::is_whitespace) {
+                Some((part, kind)) => (part, kind.trim()),
+                None => (key.trim(), ""),
+            };
+
+            let part_answers = match part {
+                "part1" => &mut answers.parts[0],
+                "part2" => &mut answers.parts[1],
+                _ => return Err(format!("line {}: unknown part '{}', expected part1 or part2", line_number, part)),
+            };
+
+            match kind {
+                "" => part_answers.correct = Some(value),
+                "too high" => part_answers.too_high.push(value),
+                "too low" => part_answers.too_low.push(value),
+                "wrong" => part_answers.wrong.push(value),
+                _ => return Err(format!("line {}: unknown answer kind '{}'", line_number, kind)),
+            }
+        }
+
+        Ok(answers)
+    }
+
+    pub fn part(&self, part: u8) -> &PartAnswers {
+        &self.parts[part as usize - 1]
+    }
+}
+
+impl PartAnswers {
+    pub fn check(&self, answer: &str) -> Verdict {
+        if let Some(correct) = &self.correct {
+            return if correct == answer {
+                Verdict::Correct
+            } else {
+                Verdict::Regression { expected: correct.clone() }
+            };
+        }
+
+        match self.known_wrong(answer) {
+            Some(reason) => Verdict::KnownWrong(reason),
+            None => Verdict::Unverified,
+        }
+    }
+
+    /// Bounds only apply to numeric answers. The lowest 'too high' and the highest 'too low' guess are the ones that matter
+    fn known_wrong(&self, answer: &str) -> Option<String> {
+        if self.wrong.iter().any(|wrong| wrong == answer) {
+            return Some(format!("{} was already rejected", answer));
+        }
+
+        let value = answer.parse::<i128>().ok()?;
+
+        let upper_bound = self.too_high.iter()
+            .filter_map(|guess| guess.parse::<i128>().ok())
+            .min();
+        if let Some(bound) = upper_bound {
+            if value >= bound {
+                return Some(format!("{} is too high, {} already was", value, bound));
+            }
+        }
+
+        let lower_bound = self.too_low.iter()
+            .filter_map(|guess| guess.parse::<i128>().ok())
+            .max();
+        if let Some(bound) = lower_bound {
+            if value <= bound {
+                return Some(format!("{} is too low, {} already was", value, bound));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+# day 3
+part1: 170068701
+part2 too high: 123486709
+part2 too high: 130000000
+part2 too low: 74207640
+part2 wrong: 90669331
+";
+
+    #[test]
+    fn accepted_answer() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.part(1).check("170068701"), Verdict::Correct);
+        assert_eq!(answers.part(1).check("170068702"), Verdict::Regression { expected: String::from("170068701") });
+    }
+
+    #[test]
+    fn known_wrong_answers() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let part2 = answers.part(2);
+
+        assert_eq!(part2.check("125000000"),
+                   Verdict::KnownWrong(String::from("125000000 is too high, 123486709 already was")));
+        assert_eq!(part2.check("74207640"),
+                   Verdict::KnownWrong(String::from("74207640 is too low, 74207640 already was")));
+        assert_eq!(part2.check("90669331"),
+                   Verdict::KnownWrong(String::from("90669331 was already rejected")));
+        assert_eq!(part2.check("90669332"), Verdict::Unverified);
+    }
+
+    // day 23's answer is a password, bounds don't apply to it
+    #[test]
+    fn non_numeric_answers() {
+        let answers = Answers::parse("part2 too high: 5\npart2 wrong: co,de\n").unwrap();
+
+        assert_eq!(answers.part(2).check("co,de,ka,ta"), Verdict::Unverified);
+        assert!(matches!(answers.part(2).check("co,de"), Verdict::KnownWrong(_)));
+    }
+
+    #[test]
+    fn malformed_lines() {
+        assert_eq!(Answers::parse("part3: 1").unwrap_err(), "line 1: unknown part 'part3', expected part1 or part2");
+        assert_eq!(Answers::parse("\npart1 too big: 1").unwrap_err(), "line 2: unknown answer kind 'too big'");
+        assert!(Answers::parse("part1 1").is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_core::DynSolution;
+
+pub struct BenchSettings {
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+/// Timings for one stage (parse, part1 or part2) of one day over all the measured iterations
+pub struct BenchRow {
+    pub day: u8,
+    pub stage: &'static str,
+    pub iterations: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub p95: Duration,
+}
+
+/// Parse, part1 and part2 each get their own row, the parts all run against one parsed input so they don't include
+/// the parsing. Part 2 only gets a row when the day has one
+pub fn bench_day(day: u8, solution: &dyn DynSolution, input: &str, settings: &BenchSettings) -> Vec<BenchRow> {
+    let mut rows = Vec::new();
+
+    rows.push(measure(day, "parse", settings, || {
+        std::hint::black_box(solution.parse(input));
+    }));
+
+    let parsed = solution.parse(input);
+
+    rows.push(measure(day, "part1", settings, || {
+        std::hint::black_box(solution.part1(&*parsed));
+    }));
+
+    if solution.has_part2() {
+        rows.push(measure(day, "part2", settings, || {
+            std::hint::black_box(solution.part2(&*parsed));
+        }));
+    }
+
+    rows
+}
+
+fn measure(day: u8, stage: &'static str, settings: &BenchSettings, mut run: impl FnMut()) -> BenchRow {
+    for _ in 0..settings.warmup {
+        run();
+    }
+
+    let mut timings: Vec<Duration> = (0..settings.iterations)
+        .map(|_| {
+            let start = Instant::now();
+            run();
+            start.elapsed()
+        })
+        .collect();
+    timings.sort();
+
+    BenchRow {
+        day,
+        stage,
+        iterations: settings.iterations,
+        min: timings[0],
+        median: percentile(&timings, 50),
+        p95: percentile(&timings, 95),
+    }
+}
+
+// nearest rank, timings are sorted and never empty
+fn percentile(timings: &[Duration], percent: usize) -> Duration {
+    let rank = (timings.len() * percent).div_ceil(100);
+    timings[rank.max(1) - 1]
+}
+
+pub fn print_table(rows: &[BenchRow]) {
+    println!("{:>3}  {:<5}  {:>5}  {:>12}  {:>12}  {:>12}", "DAY", "STAGE", "RUNS", "MIN µs", "MEDIAN µs", "P95 µs");
+
+    for row in rows {
+        println!("{:>3}  {:<5}  {:>5}  {:>12.1}  {:>12.1}  {:>12.1}",
+                 row.day, row.stage, row.iterations, micros(row.min), micros(row.median), micros(row.p95));
+    }
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1_000_000.0
+}
+
+pub fn write_csv(path: &Path, rows: &[BenchRow]) -> Result<(), String> {
+    let mut csv = String::from("day,stage,iterations,min_ns,median_ns,p95_ns\n");
+
+    for row in rows {
+        writeln!(csv, "{},{},{},{},{},{}",
+                 row.day, row.stage, row.iterations, row.min.as_nanos(), row.median.as_nanos(), row.p95.as_nanos())
+            .unwrap();
+    }
+
+    fs::write(path, csv).map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+}
+
+pub fn write_json(path: &Path, rows: &[BenchRow]) -> Result<(), String> {
+    let entries: Vec<String> = rows.iter()
+        .map(|row| {
+            format!("  {{\"day\": {}, \"stage\": \"{}\", \"iterations\": {}, \"min_ns\": {}, \"median_ns\": {}, \"p95_ns\": {}}}",
+                    row.day, row.stage, row.iterations, row.min.as_nanos(), row.median.as_nanos(), row.p95.as_nanos())
+        })
+        .collect();
+    let json = format!("[\n{}\n]\n", entries.join(",\n"));
+
+    fs::write(path, json).map_err(|err| format!("couldn't write {}: {}", path.display(), err))
+}
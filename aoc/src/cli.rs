@@ -0,0 +1,363 @@
+use std::path::PathBuf;
+
+use problem_14::{FrameFormat, CYCLE_SECONDS};
+use problem_24::{GraphFormat, WireSwap};
+
+use crate::input::InputSource;
+
+pub const USAGE: &str = "usage: aoc run --day <n> [--part <1|2>] [--input <path|->]
+       aoc run --all [--part <1|2>]
+       aoc verify [--day <n>]
+       aoc bench (--day <n> | --all) [--warmup <n>] [--iterations <n>] [--csv <path>] [--json <path>]
+       aoc new-day --day <n>
+       aoc debug [--input <path>] [--script <path>]
+       aoc graph --output <path> [--format <dot|mermaid>] [--input <path|->]
+       aoc frames --output <dir> (--seconds <n,n,...> | --from <n> --to <n> [--stride <n>]) [--format <png|pgm|gif|sheet>] [--input <path|->]
+       aoc adder [--swaps <a:b,c:d,...>] [--add <x>,<y> [--trace] | --faults] [--input <path|->]
+       aoc repair --output <path> [--input <path|->]
+
+Inputs are read from inputs/dayNN.txt unless --input points somewhere else, '-' reads stdin.
+Answers we've submitted live in answers/dayNN.txt, run won't print one that's already known to be wrong
+and verify checks every day against them.
+bench times parse, part 1 and part 2 separately and reports min/median/p95, 1 warm-up and 10 runs by default.
+new-day creates problem-N from templates/lib.rs and registers it, run it from the workspace root.
+debug steps through day 17's program, type help for the commands. --script replays commands from a file.
+graph draws day 24's circuit with one box per bit and suspicious wires in red, DOT unless --format says otherwise.
+frames draws day 14's robots at the chosen seconds into --output, a PNG each unless --format asks for PGMs, one
+animated GIF or one contact sheet. --to is included, brighter pixels have more robots on them. Seconds can't be
+negative and a range can't cover more than the 10403 seconds it takes the robots to come back round.
+adder swaps the outputs of day 24's gates for each --swaps pair, then runs the sums part 2 checks against and
+stops at the first wrong one. --add runs just the one sum instead, --trace prints every gate it ran in order with
+the values in and out. --faults lists the wires that don't sit where they would in an adder and why.
+repair writes day 24's netlist back out with part 2's swaps undone, ready to go back in through --input.";
+
+pub enum Command {
+    /// part is None when both parts should run
+    Run { days: Vec<u8>, part: Option<u8>, input: InputSource },
+    /// every day unless a single one was asked for
+    Verify { days: Vec<u8> },
+    Bench { days: Vec<u8>, warmup: u32, iterations: u32, csv: Option<PathBuf>, json: Option<PathBuf> },
+    NewDay { day: u8 },
+    /// day 17 only, commands come from the script when there is one and stdin otherwise
+    Debug { input: InputSource, script: Option<PathBuf> },
+    /// day 24 only
+    Graph { input: InputSource, output: PathBuf, format: GraphFormat },
+    /// day 14 only, output is a directory
+    Frames { input: InputSource, output: PathBuf, seconds: Vec<i64>, format: FrameFormat },
+    /// day 24 only, sum is None for the full check
+    Adder { input: InputSource, swaps: Vec<WireSwap>, sum: Option<(u64, u64)>, trace: bool, faults: bool },
+    /// day 24 only
+    Repair { input: InputSource, output: PathBuf },
+}
+
+pub fn parse_args(args: &[String]) -> Result<Command, String> {
+    let mut args = args.iter();
+
+    match args.next().map(String::as_str) {
+        Some("run") => parse_run(args),
+        Some("verify") => parse_verify(args),
+        Some("bench") => parse_bench(args),
+        Some("new-day") => parse_new_day(args),
+        Some("debug") => parse_debug(args),
+        Some("graph") => parse_graph(args),
+        Some("frames") => parse_frames(args),
+        Some("adder") => parse_adder(args),
+        Some("repair") => parse_repair(args),
+        Some(other) => Err(format!("unknown command '{}'", other)),
+        None => Err(String::from("missing command")),
+    }
+}
+
+fn parse_run<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut part = None;
+    let mut all = false;
+    let mut input = InputSource::Default;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(args.next(), "--day")?),
+            "--part" => {
+                let number = parse_number(args.next(), "--part")?;
+                if number != 1 && number != 2 {
+                    return Err(format!("--part must be 1 or 2, got {}", number));
+                }
+                part = Some(number);
+            }
+            "--all" => all = true,
+            "--input" => input = parse_input_source(args.next())?,
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    if all && !matches!(input, InputSource::Default) {
+        return Err(String::from("--input only works with a single --day"));
+    }
+
+    let days = select_days(day, all, "run")?;
+
+    Ok(Command::Run { days, part, input })
+}
+
+fn parse_verify<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut days: Vec<u8> = (1..=25).collect();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => days = vec![parse_number(args.next(), "--day")?],
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    Ok(Command::Verify { days })
+}
+
+fn parse_bench<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut day = None;
+    let mut all = false;
+    let mut warmup = 1;
+    let mut iterations = 10;
+    let mut csv = None;
+    let mut json = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(args.next(), "--day")?),
+            "--all" => all = true,
+            "--warmup" => warmup = parse_count(args.next(), "--warmup")?,
+            "--iterations" => {
+                iterations = parse_count(args.next(), "--iterations")?;
+                if iterations == 0 {
+                    return Err(String::from("--iterations must be at least 1"));
+                }
+            }
+            "--csv" => csv = Some(parse_path(args.next(), "--csv")?),
+            "--json" => json = Some(parse_path(args.next(), "--json")?),
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    let days = select_days(day, all, "bench")?;
+
+    Ok(Command::Bench { days, warmup, iterations, csv, json })
+}
+
+fn parse_new_day<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut day = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => day = Some(parse_number(args.next(), "--day")?),
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    match day {
+        Some(day) if !(1..=25).contains(&day) => Err(String::from("advent runs from day 1 to 25")),
+        Some(day) => Ok(Command::NewDay { day }),
+        None => Err(String::from("new-day needs --day <n>")),
+    }
+}
+
+fn parse_debug<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut input = InputSource::Default;
+    let mut script = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // stdin is where the commands come from, so the input can't
+            "--input" => input = InputSource::File(parse_path(args.next(), "--input")?),
+            "--script" => script = Some(parse_path(args.next(), "--script")?),
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    Ok(Command::Debug { input, script })
+}
+
+fn parse_graph<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut input = InputSource::Default;
+    let mut output = None;
+    let mut format = GraphFormat::Dot;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = parse_input_source(args.next())?,
+            "--output" => output = Some(parse_path(args.next(), "--output")?),
+            "--format" => {
+                let name = args.next().ok_or_else(|| String::from("--format needs a value"))?;
+                format = GraphFormat::from_name(name)
+                    .ok_or_else(|| format!("--format must be dot or mermaid, got '{}'", name))?;
+            }
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    let output = output.ok_or_else(|| String::from("graph needs --output <path>"))?;
+
+    Ok(Command::Graph { input, output, format })
+}
+
+fn parse_frames<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut input = InputSource::Default;
+    let mut output = None;
+    let mut format = FrameFormat::Png;
+    let mut listed = None;
+    let mut from = None;
+    let mut to = None;
+    let mut stride = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = parse_input_source(args.next())?,
+            "--output" => output = Some(parse_path(args.next(), "--output")?),
+            "--format" => {
+                let name = args.next().ok_or_else(|| String::from("--format needs a value"))?;
+                format = FrameFormat::from_name(name)
+                    .ok_or_else(|| format!("--format must be png, pgm, gif or sheet, got '{}'", name))?;
+            }
+            "--seconds" => {
+                let value = args.next().ok_or_else(|| String::from("--seconds needs a value"))?;
+                let seconds = value.split(',')
+                    .map(|second| second.trim().parse::<i64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| format!("--seconds expects numbers separated by commas, got '{}'", value))?;
+                if let Some(second) = seconds.iter().find(|&&second| second < 0) {
+                    return Err(format!("--seconds can't be negative, got {}", second));
+                }
+                listed = Some(seconds);
+            }
+            "--from" => from = Some(parse_second(args.next(), "--from")?),
+            "--to" => to = Some(parse_second(args.next(), "--to")?),
+            "--stride" => {
+                let value = parse_second(args.next(), "--stride")?;
+                if value < 1 {
+                    return Err(format!("--stride must be at least 1, got {}", value));
+                }
+                stride = Some(value);
+            }
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    let output = output.ok_or_else(|| String::from("frames needs --output <dir>"))?;
+
+    let seconds = match (listed, from, to) {
+        (Some(_), Some(_), _) | (Some(_), _, Some(_)) => return Err(String::from("--seconds can't be used with --from and --to")),
+        (Some(_), None, None) if stride.is_some() => return Err(String::from("--stride only works with --from and --to")),
+        (Some(seconds), None, None) => seconds,
+        (None, Some(from), Some(to)) if to < from => return Err(format!("--to {} comes before --from {}", to, from)),
+        (None, Some(from), Some(to)) => {
+            let stride = stride.unwrap_or(1);
+            let frames = (to - from) / stride + 1;
+            if frames > CYCLE_SECONDS {
+                return Err(format!("--from {} --to {} is {} frames, the robots repeat every {} seconds so that's the most there is to draw",
+                                   from, to, frames, CYCLE_SECONDS));
+            }
+            (from..=to).step_by(stride as usize).collect()
+        }
+        (None, _, _) => return Err(String::from("frames needs either --seconds <n,n,...> or --from <n> and --to <n>")),
+    };
+
+    Ok(Command::Frames { input, output, seconds, format })
+}
+
+fn parse_adder<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut input = InputSource::Default;
+    let mut swaps = Vec::new();
+    let mut sum = None;
+    let mut trace = false;
+    let mut faults = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = parse_input_source(args.next())?,
+            "--swaps" => {
+                let value = args.next().ok_or_else(|| String::from("--swaps needs a value"))?;
+                swaps = WireSwap::parse_list(value).map_err(|err| format!("--swaps {}", err))?;
+            }
+            "--add" => {
+                let value = args.next().ok_or_else(|| String::from("--add needs a value"))?;
+                let numbers = value.split_once(',')
+                    .and_then(|(x, y)| x.trim().parse::<u64>().ok().zip(y.trim().parse::<u64>().ok()));
+                sum = Some(numbers.ok_or_else(|| format!("--add expects two numbers separated by a comma, got '{}'", value))?);
+            }
+            "--trace" => trace = true,
+            "--faults" => faults = true,
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    if faults && sum.is_some() {
+        return Err(String::from("--faults can't be used with --add"));
+    }
+    if trace && sum.is_none() {
+        return Err(String::from("--trace only works with --add"));
+    }
+
+    Ok(Command::Adder { input, swaps, sum, trace, faults })
+}
+
+fn parse_repair<'a>(mut args: impl Iterator<Item = &'a String>) -> Result<Command, String> {
+    let mut input = InputSource::Default;
+    let mut output = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => input = parse_input_source(args.next())?,
+            "--output" => output = Some(parse_path(args.next(), "--output")?),
+            other => return Err(format!("unknown flag '{}'", other)),
+        }
+    }
+
+    let output = output.ok_or_else(|| String::from("repair needs --output <path>"))?;
+
+    Ok(Command::Repair { input, output })
+}
+
+fn select_days(day: Option<u8>, all: bool, command: &str) -> Result<Vec<u8>, String> {
+    match (day, all) {
+        (Some(day), false) => Ok(vec![day]),
+        (None, true) => Ok((1..=25).collect()),
+        (Some(_), true) => Err(String::from("--day and --all can't be used together")),
+        (None, false) => Err(format!("{} needs either --day <n> or --all", command)),
+    }
+}
+
+/// `-` is stdin, anything else is a path
+fn parse_input_source(value: Option<&String>) -> Result<InputSource, String> {
+    match value.map(String::as_str) {
+        Some("-") => Ok(InputSource::Stdin),
+        Some(path) => Ok(InputSource::File(PathBuf::from(path))),
+        None => Err(String::from("--input needs a value")),
+    }
+}
+
+fn parse_path(value: Option<&String>, flag: &str) -> Result<PathBuf, String> {
+    value.map(PathBuf::from)
+        .ok_or_else(|| format!("{} needs a value", flag))
+}
+
+fn parse_second(value: Option<&String>, flag: &str) -> Result<i64, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+
+    match value.parse::<i64>() {
+        Ok(second) if second < 0 => Err(format!("{} can't be negative, got {}", flag, second)),
+        Ok(second) => Ok(second),
+        Err(_) => Err(format!("{} expects a number, got '{}'", flag, value)),
+    }
+}
+
+fn parse_count(value: Option<&String>, flag: &str) -> Result<u32, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+
+    value.parse::<u32>()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
+
+fn parse_number(value: Option<&String>, flag: &str) -> Result<u8, String> {
+    let value = value.ok_or_else(|| format!("{} needs a value", flag))?;
+
+    value.parse::<u8>()
+        .map_err(|_| format!("{} expects a number, got '{}'", flag, value))
+}
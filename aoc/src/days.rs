@@ -0,0 +1,35 @@
+use aoc_core::DynSolution;
+
+/// Every day we have a crate for. `aoc run --all` walks these in order
+pub fn solution(day: u8) -> Option<&'static dyn DynSolution> {
+    let solution: &'static dyn DynSolution = match day {
+        1 => &problem_1::Day1,
+        2 => &problem_2::Day2,
+        3 => &problem_3::Day3,
+        4 => &problem_4::Day4,
+        5 => &problem_5::Day5,
+        6 => &problem_6::Day6,
+        7 => &problem_7::Day7,
+        8 => &problem_8::Day8,
+        9 => &problem_9::Day9,
+        10 => &problem_10::Day10,
+        11 => &problem_11::Day11,
+        12 => &problem_12::Day12,
+        13 => &problem_13::Day13,
+        14 => &problem_14::Day14,
+        15 => &problem_15::Day15,
+        16 => &problem_16::Day16,
+        17 => &problem_17::Day17,
+        18 => &problem_18::Day18,
+        19 => &problem_19::Day19,
+        20 => &problem_20::Day20,
+        21 => &problem_21::Day21,
+        22 => &problem_22::Day22,
+        23 => &problem_23::Day23,
+        24 => &problem_24::Day24,
+        25 => &problem_25::Day25,
+        _ => return None,
+    };
+
+    Some(solution)
+}
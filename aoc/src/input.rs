@@ -0,0 +1,37 @@
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Puzzle inputs aren't committed, drop each one in here as dayNN.txt
+pub const INPUTS_DIR: &str = "inputs";
+
+/// Where a day's puzzle input comes from
+pub enum InputSource {
+    /// inputs/dayNN.txt, relative to wherever aoc is run from
+    Default,
+    File(PathBuf),
+    Stdin,
+}
+
+pub fn default_path(day: u8) -> PathBuf {
+    Path::new(INPUTS_DIR).join(format!("day{:02}.txt", day))
+}
+
+pub fn load(day: u8, source: &InputSource) -> Result<String, String> {
+    match source {
+        InputSource::Default => read_file(&default_path(day))
+            .map_err(|err| format!("{} (download it from https://adventofcode.com/2024/day/{}/input)", err, day)),
+        InputSource::File(path) => read_file(path),
+        InputSource::Stdin => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)
+                .map_err(|err| format!("couldn't read stdin: {}", err))?;
+            Ok(input)
+        }
+    }
+}
+
+fn read_file(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read {}: {}", path.display(), err))
+}
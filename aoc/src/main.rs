@@ -0,0 +1,345 @@
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+use aoc_core::DynSolution;
+use aoc_vm::Debugger;
+use problem_14::FrameFormat;
+use problem_24::{GraphFormat, WireGates, WireSwap};
+
+use crate::answers::{Answers, Verdict};
+use crate::bench::BenchSettings;
+use crate::cli::Command;
+use crate::input::InputSource;
+
+mod answers;
+mod bench;
+mod cli;
+mod days;
+mod input;
+mod scaffold;
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let command = match cli::parse_args(&args) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, cli::USAGE);
+            process::exit(2);
+        }
+    };
+
+    match command {
+        Command::Run { days, part, input } => {
+            let mut failed = false;
+
+            for day in days {
+                if let Err(message) = run_day(day, part, &input) {
+                    eprintln!("DAY {} SKIPPED {}", day, message);
+                    failed = true;
+                }
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::Verify { days } => {
+            if !verify(&days) {
+                process::exit(1);
+            }
+        }
+        Command::Bench { days, warmup, iterations, csv, json } => {
+            let settings = BenchSettings { warmup, iterations };
+            let mut rows = Vec::new();
+            let mut failed = false;
+
+            for day in days {
+                match bench_inputs(day) {
+                    Ok((solution, input)) => rows.extend(bench::bench_day(day, solution, &input, &settings)),
+                    Err(message) => {
+                        eprintln!("DAY {} SKIPPED {}", day, message);
+                        failed = true;
+                    }
+                }
+            }
+
+            bench::print_table(&rows);
+
+            if let Some(path) = csv {
+                if let Err(message) = bench::write_csv(&path, &rows) {
+                    eprintln!("{}", message);
+                    failed = true;
+                }
+            }
+
+            if let Some(path) = json {
+                if let Err(message) = bench::write_json(&path, &rows) {
+                    eprintln!("{}", message);
+                    failed = true;
+                }
+            }
+
+            if failed {
+                process::exit(1);
+            }
+        }
+        Command::NewDay { day } => match scaffold::new_day(day) {
+            Ok(written) => {
+                for path in written {
+                    println!("WROTE {}", path.display());
+                }
+            }
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        },
+        Command::Debug { input, script } => {
+            if let Err(message) = debug(&input, script) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Graph { input, output, format } => {
+            if let Err(message) = graph(&input, &output, format) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Frames { input, output, seconds, format } => {
+            if let Err(message) = frames(&input, &output, &seconds, format) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Adder { input, swaps, sum, trace, faults } => {
+            if let Err(message) = adder(&input, &swaps, sum, trace, faults) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+        Command::Repair { input, output } => {
+            if let Err(message) = repair(&input, &output) {
+                eprintln!("{}", message);
+                process::exit(1);
+            }
+        }
+    }
+}
+
+fn run_day(day: u8, part: Option<u8>, source: &InputSource) -> Result<(), String> {
+    let solution = days::solution(day)
+        .ok_or_else(|| String::from("no solution registered"))?;
+    let input = input::load(day, source)?;
+    // recorded answers only mean something for the real puzzle input
+    let answers = match source {
+        InputSource::Default => Answers::load(day)?,
+        _ => Answers::default(),
+    };
+
+    // both parts share the one parse, so the times below are just the solving
+    let parsed = solution.parse(&input);
+
+    if part != Some(2) {
+        let start = Instant::now();
+        let answer = solution.part1(&*parsed);
+        print_answer(day, 1, &answer, &answers, start.elapsed());
+    }
+
+    if part != Some(1) {
+        let start = Instant::now();
+        match solution.part2(&*parsed) {
+            Some(answer) => print_answer(day, 2, &answer, &answers, start.elapsed()),
+            None => println!("DAY {} PART 2 has no solver", day),
+        }
+    }
+
+    Ok(())
+}
+
+fn print_answer(day: u8, part: u8, answer: &str, answers: &Answers, elapsed: Duration) {
+    match answers.part(part).check(answer) {
+        Verdict::Correct | Verdict::Unverified => println!("DAY {} PART {} {} ({:?})", day, part, answer, elapsed),
+        Verdict::Regression { expected } => {
+            println!("DAY {} PART {} REFUSED {} isn't the accepted answer {} ({:?})", day, part, answer, expected, elapsed)
+        }
+        Verdict::KnownWrong(reason) => println!("DAY {} PART {} REFUSED {} ({:?})", day, part, reason, elapsed),
+    }
+}
+
+/// Steps through day 17's program, reading commands from the script if there is one
+fn debug(source: &InputSource, script: Option<PathBuf>) -> Result<(), String> {
+    let input = input::load(17, source)?;
+    let mut debugger = Debugger::new(problem_17::machine(&input));
+
+    let session = match script {
+        Some(path) => {
+            let script = fs::read_to_string(&path)
+                .map_err(|err| format!("couldn't read {}: {}", path.display(), err))?;
+            debugger.run_session(script.as_bytes(), io::stdout(), false)
+        }
+        None => debugger.run_session(io::stdin().lock(), io::stdout(), true),
+    };
+
+    session.map_err(|err| format!("debug session failed: {}", err))
+}
+
+fn graph(source: &InputSource, output: &Path, format: GraphFormat) -> Result<(), String> {
+    let input = input::load(24, source)?;
+    let graph = problem_24::circuit_graph(&input, format)
+        .map_err(|err| format!("couldn't read the circuit: {}", err))?;
+
+    fs::write(output, graph).map_err(|err| format!("couldn't write {}: {}", output.display(), err))?;
+    println!("wrote {}", output.display());
+
+    Ok(())
+}
+
+/// Writes the images into `output`, making it if needed. Only says how many there were, printing every file name is
+/// the terminal flood this is meant to avoid
+fn frames(source: &InputSource, output: &Path, seconds: &[i64], format: FrameFormat) -> Result<(), String> {
+    let input = input::load(14, source)?;
+    fs::create_dir_all(output).map_err(|err| format!("couldn't create {}: {}", output.display(), err))?;
+
+    let files = problem_14::robot_frames(&input, seconds, format);
+    for (name, image) in &files {
+        let path = output.join(name);
+        fs::write(&path, image).map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+    }
+
+    match &files[..] {
+        [(name, _)] => println!("wrote {}", output.join(name).display()),
+        _ => println!("wrote {} frames to {}", files.len(), output.display()),
+    }
+
+    Ok(())
+}
+
+/// Rewires day 24's circuit and either checks it adds like part 2 does, runs the one sum, or lists the wires that
+/// look out of place. A wrong sum is an error so scripts can tell from the exit code
+fn adder(source: &InputSource, swaps: &[WireSwap], sum: Option<(u64, u64)>, trace: bool, faults: bool) -> Result<(), String> {
+    let input = input::load(24, source)?;
+    let mut circuit = WireGates::parse(&input)
+        .map_err(|err| format!("couldn't read the circuit: {}", err))?;
+    circuit.apply_swaps(swaps)?;
+
+    if faults {
+        let faults = circuit.find_adder_faults();
+        for fault in &faults {
+            println!("{}", fault);
+        }
+        println!("{} suspicious wires", faults.len());
+        return Ok(());
+    }
+
+    match sum {
+        Some((x, y)) => {
+            if trace {
+                let mut traced = circuit.clone();
+                traced.set_inputs(x, y).map_err(|err| format!("circuit can't be evaluated: {}", err))?;
+                let evaluation = traced.evaluate().map_err(|err| format!("circuit can't be evaluated: {}", err))?;
+                println!("{}", evaluation.trace());
+            }
+            let z = circuit.add(x, y).map_err(|err| format!("circuit can't be evaluated: {}", err))?;
+            if x.checked_add(y) != Some(z) {
+                return Err(format!("FAIL {} + {} came out as {}", x, y, z));
+            }
+            println!("PASS {} + {} = {}", x, y, z);
+        }
+        None => {
+            circuit.check_adder(problem_24::FUZZ_ROUNDS, problem_24::FUZZ_SEED)
+                .map_err(|mismatch| format!("FAIL {}", mismatch))?;
+            println!("PASS every bit and {} random sums add up", problem_24::FUZZ_ROUNDS);
+        }
+    }
+
+    Ok(())
+}
+
+fn repair(source: &InputSource, output: &Path) -> Result<(), String> {
+    let input = input::load(24, source)?;
+    let netlist = problem_24::repaired_netlist(&input)
+        .map_err(|err| format!("couldn't repair the circuit: {}", err))?;
+
+    fs::write(output, netlist).map_err(|err| format!("couldn't write {}: {}", output.display(), err))?;
+    println!("wrote {}", output.display());
+
+    Ok(())
+}
+
+/// bench always uses the real puzzle input
+fn bench_inputs(day: u8) -> Result<(&'static dyn DynSolution, String), String> {
+    let solution = days::solution(day)
+        .ok_or_else(|| String::from("no solution registered"))?;
+    let input = input::load(day, &InputSource::Default)?;
+
+    Ok((solution, input))
+}
+
+#[derive(Default)]
+struct VerifyTally {
+    passed: u32,
+    failed: u32,
+    regressed: u32,
+    unverified: u32,
+    skipped: u32,
+}
+
+/// Runs both parts of every day against answers/ and reports each one, true when nothing failed or regressed
+fn verify(days: &[u8]) -> bool {
+    let mut tally = VerifyTally::default();
+
+    for &day in days {
+        if let Err(message) = verify_day(day, &mut tally) {
+            println!("DAY {} SKIPPED {}", day, message);
+            tally.skipped += 1;
+        }
+    }
+
+    println!("{} passed, {} failed, {} regressed, {} unverified, {} days skipped",
+             tally.passed, tally.failed, tally.regressed, tally.unverified, tally.skipped);
+
+    tally.failed == 0 && tally.regressed == 0
+}
+
+fn verify_day(day: u8, tally: &mut VerifyTally) -> Result<(), String> {
+    let solution = days::solution(day)
+        .ok_or_else(|| String::from("no solution registered"))?;
+    let input = input::load(day, &InputSource::Default)?;
+    let answers = Answers::load(day)?;
+
+    let parsed = solution.parse(&input);
+    let results = [Some(solution.part1(&*parsed)), solution.part2(&*parsed)];
+
+    for (part, answer) in (1..=2).zip(results) {
+        let Some(answer) = answer else {
+            continue;
+        };
+
+        match answers.part(part).check(&answer) {
+            Verdict::Correct => {
+                println!("DAY {} PART {} PASS {}", day, part, answer);
+                tally.passed += 1;
+            }
+            Verdict::Regression { expected } => {
+                println!("DAY {} PART {} REGRESSION got {}, expected {}", day, part, answer, expected);
+                tally.regressed += 1;
+            }
+            Verdict::KnownWrong(reason) => {
+                println!("DAY {} PART {} FAIL {}", day, part, reason);
+                tally.failed += 1;
+            }
+            Verdict::Unverified => {
+                println!("DAY {} PART {} UNVERIFIED {}", day, part, answer);
+                tally.unverified += 1;
+            }
+        }
+    }
+
+    Ok(())
+}
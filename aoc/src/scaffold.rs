@@ -0,0 +1,242 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+
+/// Every new day starts from this, `__DAY__` gets swapped for the day number
+const LIB_TEMPLATE: &str = include_str!("../../templates/lib.rs");
+const DAY_PLACEHOLDER: &str = "__DAY__";
+
+const WORKSPACE_MANIFEST: &str = "Cargo.toml";
+const RUNNER_MANIFEST: &str = "aoc/Cargo.toml";
+const RUNNER_DAYS: &str = "aoc/src/days.rs";
+
+/// Creates problem-N from the template and hooks it up to the workspace and the runner. Has to be run from the
+/// workspace root, same as everything else. Returns every file it created or changed
+pub fn new_day(day: u8) -> Result<Vec<PathBuf>, String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} isn't a puzzle, advent runs from day 1 to 25", day));
+    }
+
+    let crate_dir = PathBuf::from(crate_name(day));
+    if crate_dir.exists() {
+        return Err(format!("{} already exists", crate_dir.display()));
+    }
+
+    // work out every edit before writing anything so a bad file doesn't leave the workspace half registered
+    let originals = [
+        (WORKSPACE_MANIFEST, read(WORKSPACE_MANIFEST)?),
+        (RUNNER_MANIFEST, read(RUNNER_MANIFEST)?),
+        (RUNNER_DAYS, read(RUNNER_DAYS)?),
+    ];
+    let mut edits = vec![
+        (crate_dir.join("Cargo.toml"), crate_manifest(day)),
+        (crate_dir.join("src").join("lib.rs"), render_lib(day)),
+        (PathBuf::from(WORKSPACE_MANIFEST), add_workspace_member(&originals[0].1, day)?),
+        (PathBuf::from(RUNNER_MANIFEST), add_runner_dependency(&originals[1].1, day)?),
+        (PathBuf::from(RUNNER_DAYS), add_days_arm(&originals[2].1, day)?),
+    ];
+
+    // an input that's already been downloaded is left alone
+    let input_path = input::default_path(day);
+    if !input_path.exists() {
+        edits.push((input_path, String::new()));
+    }
+
+    let mut written = Vec::new();
+    for (path, contents) in &edits {
+        if let Err(err) = write(path, contents, &mut written) {
+            return match roll_back(&crate_dir, &written, &originals) {
+                Ok(()) => Err(format!("{}, undid everything new-day had written", err)),
+                Err(rollback_err) => Err(format!("{}, and then {} while undoing the rest", err, rollback_err)),
+            };
+        }
+    }
+
+    Ok(written)
+}
+
+/// Puts the files new-day changed back how they were and deletes the ones it made, including the crate directory
+fn roll_back(crate_dir: &Path, written: &[PathBuf], originals: &[(&str, String)]) -> Result<(), String> {
+    for path in written.iter().rev() {
+        match originals.iter().find(|(original, _)| Path::new(original) == path) {
+            Some((_, contents)) => fs::write(path, contents)
+                .map_err(|err| format!("couldn't restore {}: {}", path.display(), err))?,
+            None => fs::remove_file(path)
+                .map_err(|err| format!("couldn't remove {}: {}", path.display(), err))?,
+        }
+    }
+
+    if crate_dir.exists() {
+        fs::remove_dir_all(crate_dir).map_err(|err| format!("couldn't remove {}: {}", crate_dir.display(), err))?;
+    }
+
+    Ok(())
+}
+
+fn crate_name(day: u8) -> String {
+    format!("problem-{}", day)
+}
+
+fn crate_manifest(day: u8) -> String {
+    format!("[package]
+name = \"{}\"
+version = \"0.1.0\"
+edition = \"2021\"
+
+[dependencies]
+aoc-core = {{ path = \"../aoc-core\" }}
+", crate_name(day))
+}
+
+fn render_lib(day: u8) -> String {
+    LIB_TEMPLATE.replace(DAY_PLACEHOLDER, &day.to_string())
+}
+
+/// New members go at the end of the `members = [...]` list
+fn add_workspace_member(manifest: &str, day: u8) -> Result<String, String> {
+    let member = format!("\"{}\"", crate_name(day));
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    let members_start = lines.iter()
+        .position(|line| line.trim_start().starts_with("members = ["))
+        .ok_or_else(|| format!("{} has no members list", WORKSPACE_MANIFEST))?;
+    let members_end = lines[members_start..].iter()
+        .position(|line| line.trim() == "]")
+        .map(|offset| members_start + offset)
+        .ok_or_else(|| format!("{} members list is never closed", WORKSPACE_MANIFEST))?;
+
+    if lines[members_start..members_end].iter().any(|line| line.trim().trim_end_matches(',') == member) {
+        return Err(format!("{} is already a workspace member", crate_name(day)));
+    }
+
+    let member_line = format!("    {},", member);
+    Ok(insert_line(&lines, members_end, &member_line))
+}
+
+/// The runner depends on every day, the new one goes after the last problem-N
+fn add_runner_dependency(manifest: &str, day: u8) -> Result<String, String> {
+    let name = crate_name(day);
+    let lines: Vec<&str> = manifest.lines().collect();
+
+    if lines.iter().any(|line| line.split_whitespace().next() == Some(name.as_str())) {
+        return Err(format!("{} already depends on {}", RUNNER_MANIFEST, name));
+    }
+
+    let last_problem = lines.iter()
+        .rposition(|line| line.starts_with("problem-"))
+        .ok_or_else(|| format!("{} doesn't depend on any days", RUNNER_MANIFEST))?;
+
+    let dependency = format!("{} = {{ path = \"../{}\" }}", name, name);
+    Ok(insert_line(&lines, last_problem + 1, &dependency))
+}
+
+/// Adds `N => &problem_N::DayN,` just above the catch-all arm in days::solution
+fn add_days_arm(days: &str, day: u8) -> Result<String, String> {
+    let lines: Vec<&str> = days.lines().collect();
+
+    let arm_start = format!("{} =>", day);
+    if lines.iter().any(|line| line.trim_start().starts_with(&arm_start)) {
+        return Err(format!("{} already has day {}", RUNNER_DAYS, day));
+    }
+
+    let catch_all = lines.iter()
+        .position(|line| line.trim_start().starts_with("_ =>"))
+        .ok_or_else(|| format!("{} has no catch-all arm to add day {} above", RUNNER_DAYS, day))?;
+
+    let arm = format!("        {} => &problem_{}::Day{},", day, day, day);
+    Ok(insert_line(&lines, catch_all, &arm))
+}
+
+fn insert_line(lines: &[&str], index: usize, line: &str) -> String {
+    let mut updated: Vec<&str> = lines.to_vec();
+    updated.insert(index, line);
+
+    let mut contents = updated.join("\n");
+    contents.push('\n');
+    contents
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("couldn't read {} (new-day has to run from the workspace root): {}", path, err))
+}
+
+fn write(path: &Path, contents: &str, written: &mut Vec<PathBuf>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("couldn't create {}: {}", parent.display(), err))?;
+    }
+
+    fs::write(path, contents)
+        .map_err(|err| format!("couldn't write {}: {}", path.display(), err))?;
+    written.push(path.to_path_buf());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_is_filled_in() {
+        let lib = render_lib(26);
+
+        assert!(lib.contains("pub struct Day26;"));
+        assert!(lib.contains("impl Solution for Day26 {"));
+        assert!(lib.contains("https://adventofcode.com/2024/day/26"));
+        assert!(!lib.contains(DAY_PLACEHOLDER));
+    }
+
+    #[test]
+    fn only_real_days() {
+        assert!(new_day(0).unwrap_err().contains("1 to 25"));
+        assert!(new_day(26).unwrap_err().contains("1 to 25"));
+    }
+
+    #[test]
+    fn roll_back_restores_and_removes() {
+        let dir = std::env::temp_dir().join(format!("aoc-roll-back-{}", std::process::id()));
+        let crate_dir = dir.join("problem-26");
+        let created = crate_dir.join("src").join("lib.rs");
+        let changed = dir.join("Cargo.toml");
+        fs::create_dir_all(created.parent().unwrap()).unwrap();
+        fs::write(&created, "pub struct Day26;").unwrap();
+        fs::write(&changed, "members = [\"problem-26\"]").unwrap();
+
+        let changed_name = changed.to_str().unwrap();
+        roll_back(&crate_dir, &[created.clone(), changed.clone()], &[(changed_name, String::from("members = []"))]).unwrap();
+
+        assert!(!crate_dir.exists());
+        assert_eq!(fs::read_to_string(&changed).unwrap(), "members = []");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn registers_workspace_member() {
+        let manifest = "[workspace]\nmembers = [\n    \"aoc\",\n    \"problem-1\",\n]\n\n[profile.release]\n";
+
+        assert_eq!(add_workspace_member(manifest, 2).unwrap(),
+                   "[workspace]\nmembers = [\n    \"aoc\",\n    \"problem-1\",\n    \"problem-2\",\n]\n\n[profile.release]\n");
+        assert!(add_workspace_member(manifest, 1).is_err());
+    }
+
+    #[test]
+    fn registers_runner_dependency() {
+        let manifest = "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nproblem-1 = { path = \"../problem-1\" }\n";
+
+        assert_eq!(add_runner_dependency(manifest, 2).unwrap(),
+                   "[dependencies]\naoc-core = { path = \"../aoc-core\" }\nproblem-1 = { path = \"../problem-1\" }\nproblem-2 = { path = \"../problem-2\" }\n");
+        assert!(add_runner_dependency(manifest, 1).is_err());
+    }
+
+    #[test]
+    fn registers_days_arm() {
+        let days = "    match day {\n        1 => &problem_1::Day1,\n        _ => return None,\n    };\n";
+
+        assert_eq!(add_days_arm(days, 2).unwrap(),
+                   "    match day {\n        1 => &problem_1::Day1,\n        2 => &problem_2::Day2,\n        _ => return None,\n    };\n");
+        assert!(add_days_arm(days, 1).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use aoc_core::Solution;
+
+// PROBLEM STATEMENT: https://adventofcode.com/2024/day/1
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed = (Vec<i32>, Vec<i32>);
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_lists(input)
+    }
+
+    fn part1(&self, (left, right): &Self::Parsed) -> String {
+        let sorted_pairs = get_sorted_pairs(left, right);
+        calc_distance(&sorted_pairs).to_string()
+    }
+
+    fn part2(&self, (left, right): &Self::Parsed) -> String {
+        solve_part_2(left, right).to_string()
+    }
+}
+
+// the left and right columns, in the order they came in
+fn parse_lists(input: &str) -> (Vec<i32>, Vec<i32>) {
+    let mut left_input = Vec::new();
+    let mut right_input: Vec<i32> = Vec::new();
+
+    for line in input.lines() {
+        // Rust std has a built-in splitter on white space :O
+        let num_pair: Vec<&str> = line
+            .split_whitespace()
+            .collect();
+
+
+        if num_pair.len() == 2 {
+            // lesgo, we can use this finally - https://doc.rust-lang.org/book/ch06-03-if-let.html
+            if let (Ok(first_num), Ok(second_num)) = (
+                num_pair[0].parse::<i32>(),
+                num_pair[1].parse::<i32>()
+            ) {
+                left_input.push(first_num);
+                right_input.push(second_num);
+            }
+        }
+    }
+
+    // Neat we can just return a tuple without extra instantiation syntax
+    (left_input, right_input)
+}
+
+fn get_sorted_pairs(left: &[i32], right: &[i32]) -> Vec<(i32, i32)> {
+    let mut left_input = left.to_vec();
+    let mut right_input = right.to_vec();
+
+    left_input.sort();
+    right_input.sort();
+
+    left_input.into_iter()
+        .zip(right_input)
+        .collect()
+}
+
+fn calc_distance(sorted_pairs: &[(i32, i32)]) -> i32 {
+    sorted_pairs.iter()
+        .map(|(left, right)| (left - right).abs())
+        .sum()
+}
+
+// we can do a hash map calc of the right side
+fn solve_part_2(left_input: &[i32], right: &[i32]) -> i32 {
+    let right_input = mapify_right(right);
+
+    left_input.iter()
+        .map(|val| val * right_input.get(val).unwrap_or(&0))
+        .sum()
+}
+
+fn mapify_right(right: &[i32]) -> HashMap<i32, i32> {
+    let mut right_input: HashMap<i32, i32> = HashMap::new();
+
+    for &second_num in right {
+        // a little more verbose I think than Java to increment a current value but alright
+        right_input.entry(second_num)
+            .and_modify(|value| *value += 1)
+            .or_insert(1);
+    }
+
+    right_input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+    #[test]
+    fn part_1_example() {
+        let (left, right) = parse_lists(EXAMPLE);
+        assert_eq!(calc_distance(&get_sorted_pairs(&left, &right)), 11);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let (left, right) = parse_lists(EXAMPLE);
+        assert_eq!(solve_part_2(&left, &right), 31);
+    }
+}
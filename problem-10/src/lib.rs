@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use aoc_core::Solution;
+use aoc_grid::{Grid, Pos};
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed = Maze;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, maze: &Self::Parsed) -> String {
+        solve_part_1(maze).to_string()
+    }
+
+    fn part2(&self, maze: &Self::Parsed) -> String {
+        solve_part_2(maze).to_string()
+    }
+}
+
+fn solve_part_1(maze: &Maze) -> u32 {
+    let mut total_score = 0;
+    for starting_pos in &maze.starting_points {
+        let position_score = get_trail_scores(maze, 0, starting_pos, &mut HashSet::new());
+        total_score += position_score;
+    }
+
+    total_score
+}
+
+fn parse_input(input: &str) -> Maze {
+    // anything that isn't a digit is impassable, 11 is too high to ever step onto
+    let points = Grid::parse_with(input, |character| character.to_digit(10).unwrap_or(11) as i32);
+    let starting_points = points.find_all(&0).collect();
+
+    Maze {
+        starting_points,
+        points
+    }
+}
+
+/// Create a recursive call in every direction from every point. If we reach a 9, we add it to the hashset and return 1. If we've already visited the 9
+/// we just break out and return 0 to not increment our score.
+fn get_trail_scores(maze: &Maze, current_height: i32, current_position: &Pos, nines: &mut HashSet<Pos>) -> u32 {
+    // Base case: found a valid path to 9
+    if current_height == 9 && !nines.contains(current_position) {
+        nines.insert(*current_position);
+        return 1;
+    } else if current_height == 9 {
+        return 0;
+    }
+
+    let mut total = 0;
+
+    for new_position in maze.points.neighbours4(*current_position) {
+        let height = maze.points[new_position];
+        let diff = height - current_height;
+        if diff == 1 {
+            let score = get_trail_scores(maze, height, &new_position, nines);
+            total += score;
+        }
+    }
+
+    total
+}
+
+fn solve_part_2(maze: &Maze) -> u32 {
+    let mut total_score = 0;
+    for starting_pos in &maze.starting_points {
+        let position_score = get_overlapping_trail_scores(maze, 0, starting_pos);
+        total_score += position_score;
+    }
+
+    total_score
+}
+
+fn get_overlapping_trail_scores(maze: &Maze, current_height: i32, current_position: &Pos) -> u32 {
+    // Base case: found a valid path to 9
+    if current_height == 9 {
+        return 1;
+    }
+
+    let mut total = 0;
+
+    for new_position in maze.points.neighbours4(*current_position) {
+        let height = maze.points[new_position];
+        let diff = height - current_height;
+        if diff == 1 {
+            let score = get_overlapping_trail_scores(maze, height, &new_position);
+            total += score;
+        }
+    }
+
+    total
+}
+
+pub struct Maze {
+    // starting points have a height of zero
+    starting_points: Vec<Pos>,
+    // value is height
+    points: Grid<i32>
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 36);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 81);
+    }
+}
@@ -1,28 +1,34 @@
 use std::collections::{HashMap};
-use std::time::{SystemTime, UNIX_EPOCH};
 
-mod gimme_input;
+use aoc_core::Solution;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+pub struct Day11;
 
-    println!("PART 1 {:?}", solve_part_for_blinks(gimme_input::BABY_INPUT, 1));
-    //println!("PART 2 {}", solve_part_for_blinks(gimme_input::INPUT, 75));
+impl Solution for Day11 {
+    type Parsed = StoneCounter;
 
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, stones: &Self::Parsed) -> String {
+        solve_part_for_blinks(stones, 25).to_string()
+    }
 
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+    fn part2(&self, stones: &Self::Parsed) -> String {
+        solve_part_for_blinks(stones, 75).to_string()
+    }
 }
 
-fn solve_part_for_blinks(input: &str, blinks: u32) -> u64 {
-    let mut stones = parse_input(input);
+fn solve_part_for_blinks(stones: &StoneCounter, blinks: u32) -> u64 {
+    let mut stones = stones.clone();
 
 
     for _i in 0..blinks {
         stones.blink();
     }
 
-    stones.get_stone_count() as u64
+    stones.get_stone_count()
 }
 
 fn parse_input(input: &str) -> StoneCounter {
@@ -34,7 +40,9 @@ fn parse_input(input: &str) -> StoneCounter {
 
     stones
 }
-struct StoneCounter {
+
+#[derive(Clone)]
+pub struct StoneCounter {
     /// Frequency map
     stone_counts: HashMap<u64, u64>,
 }
@@ -73,7 +81,7 @@ impl StoneCounter {
         }
 
         let digit_count = Self::count_digits(&n);
-        if digit_count % 2 == 0 {
+        if digit_count.is_multiple_of(2) {
             return Self::split_number(n);
         }
 
@@ -111,4 +119,15 @@ impl StoneCounter {
     fn get_stone_count(&self) -> u64 {
         self.stone_counts.values().sum()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_blinks() {
+        assert_eq!(solve_part_for_blinks(&parse_input("125 17"), 6), 22);
+        assert_eq!(solve_part_for_blinks(&parse_input("125 17"), 25), 55312);
+    }
+}
@@ -1,41 +1,45 @@
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::time::{SystemTime, UNIX_EPOCH};
 
-const OUTER_CORNERS: [((i32, i32), (i32, i32)); 4] = [
-    ((-1, 0), (0, 1)),  // above and to the right
-    ((1, 0), (0, 1)),   // below and to the right
-    ((-1, 0), (0, -1)), // above and to the left
-    ((1, 0), (0, -1)),  // below and to the left
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Pos};
+
+const OUTER_CORNERS: [(Direction, Direction); 4] = [
+    (Direction::Up, Direction::Right),   // above and to the right
+    (Direction::Down, Direction::Right), // below and to the right
+    (Direction::Up, Direction::Left),    // above and to the left
+    (Direction::Down, Direction::Left),  // below and to the left
 ];
 
-const INNER_CORNERS: [((i32, i32), (i32, i32), (i32, i32)); 4] = [
-    ((-1, 0), (0, 1), (-1, 1)),   // above, to the right, diagonal top right
-    ((1, 0), (0, 1), (1, 1)),     // below, to the right, diagonal bottom right
-    ((-1, 0), (0, -1), (-1, -1)), // above, to the left, diagonal top left
-    ((1, 0), (0, -1), (1, -1)),   // below, to the left, diagonal bottom left
+const INNER_CORNERS: [(Direction, Direction, Direction); 4] = [
+    (Direction::Up, Direction::Right, Direction::UpRight),     // above, to the right, diagonal top right
+    (Direction::Down, Direction::Right, Direction::DownRight), // below, to the right, diagonal bottom right
+    (Direction::Up, Direction::Left, Direction::UpLeft),       // above, to the left, diagonal top left
+    (Direction::Down, Direction::Left, Direction::DownLeft),   // below, to the left, diagonal bottom left
 ];
 
-mod gimme_input;
+pub struct Day12;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+impl Solution for Day12 {
+    type Parsed = Garden;
 
-    //println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT));
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT));
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
 
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fn part1(&self, garden: &Self::Parsed) -> String {
+        solve_part_1(garden).to_string()
+    }
 
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+    fn part2(&self, garden: &Self::Parsed) -> String {
+        solve_part_2(garden).to_string()
+    }
 }
 
-fn solve_part_1(input: &str) -> u32 {
-    let garden = parse_input(input);
-
+fn solve_part_1(garden: &Garden) -> u32 {
     let mut price = 0;
-    for (character, counts) in &garden.characters {
+    for character in garden.characters.keys() {
         let perimeter = garden.calculate_perimeter(character);
 
-        println!("Perimeter for {} :: {:?} :: {} counts", character, perimeter, counts);
         let price_for_char: u32 = perimeter.iter()
             .map(|(perimeter, area)| perimeter * area)
             .sum();
@@ -46,26 +50,19 @@ fn solve_part_1(input: &str) -> u32 {
 }
 
 fn parse_input(input: &str) -> Garden {
-    let mut data: Vec<Vec<char>> = Vec::new();
+    let data = Grid::parse(input);
     let mut character_freq = HashMap::new();
 
-    for line in input.lines() {
-        let mut row_data = Vec::new();
-        for character in line.chars() {
-            row_data.push(character);
-            *character_freq.entry(character).or_insert(0) += 1;
-        }
-        data.push(row_data);
+    for (_, &character) in data.iter() {
+        *character_freq.entry(character).or_insert(0) += 1;
     }
 
     Garden::new(data, character_freq)
 }
 
-fn solve_part_2(input: &str) -> u32 {
-    let garden = parse_input(input);
-
+fn solve_part_2(garden: &Garden) -> u32 {
     let mut price = 0;
-    for (character, _counts) in &garden.characters {
+    for character in garden.characters.keys() {
         let regions = garden.find_total_sides(character);
       //  println!("{:?} :: {:?}", character, regions);
         let price_for_char: u32 = regions.iter()
@@ -76,35 +73,22 @@ fn solve_part_2(input: &str) -> u32 {
     price
 }
 
-#[derive(PartialEq, Eq, Hash, Clone, Copy, Debug)]
-struct Pos {
-    row: i32,
-    col: i32,
-}
-struct Garden {
-    data: Vec<Vec<char>>,
+pub struct Garden {
+    data: Grid<char>,
     characters: HashMap<char, usize>,
-    rows: i32,
-    cols: i32,
 }
 
 impl Garden {
-    fn new(data: Vec<Vec<char>>, characters: HashMap<char, usize>) -> Self {
-        let rows = data.len() as i32;
-        let cols = data[0].len() as i32;
-        Garden { data, characters, rows, cols }
+    fn new(data: Grid<char>, characters: HashMap<char, usize>) -> Self {
+        Garden { data, characters }
     }
 
     fn in_bounds(&self, pos: &Pos) -> bool {
-        pos.row >= 0 && pos.row < self.rows && pos.col >= 0 && pos.col < self.cols
+        self.data.in_bounds(*pos)
     }
 
     fn get(&self, pos: &Pos) -> Option<char> {
-        if self.in_bounds(pos) {
-            Some(self.data[pos.row as usize][pos.col as usize])
-        } else {
-            None
-        }
+        self.data.get(*pos).copied()
     }
 
 
@@ -112,12 +96,9 @@ impl Garden {
         let mut visited = HashSet::new();
         let mut perimeters = Vec::new();
 
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                let pos = Pos { row, col };
-                if !visited.contains(&pos) && self.get(&pos) == Some(*target) {
-                    perimeters.push(self.perimeter_recursive(&pos, target, &mut visited, &mut 0));
-                }
+        for pos in self.data.find_all(target) {
+            if !visited.contains(&pos) {
+                perimeters.push(self.perimeter_recursive(&pos, target, &mut visited, &mut 0));
             }
         }
 
@@ -132,19 +113,15 @@ impl Garden {
         if !self.in_bounds(pos) || visited.contains(pos) || self.get(pos) != Some(*target) {
             return (0, *char_count);
         }
-        visited.insert(Pos { row: pos.row, col: pos.col });
+        visited.insert(*pos);
         *char_count += 1;
 
         let mut like_neighbors = 0;
-        let directions = [(0, 1), (1, 0), (0, -1), (-1, 0)];
 
         // count the like neighbors
-        for (x, y) in directions {
-            let next = Pos { row: pos.row + x, col: pos.col + y };
-            if let Some(ch) = self.get(&next) {
-                if ch == *target {
-                    like_neighbors += 1;
-                }
+        for next in pos.neighbours4() {
+            if self.get(&next) == Some(*target) {
+                like_neighbors += 1;
             }
         }
 
@@ -159,12 +136,9 @@ impl Garden {
 
         // Recursively explore neighbors
         let mut total_perimeter = local_perimeter;
-        let mut total_area = *char_count;
-        for (x, y) in directions {
-            let next = Pos { row: pos.row + x, col: pos.col + y };
-            let (perimeter, area) = self.perimeter_recursive(&next, target, visited, char_count);
+        for next in pos.neighbours4() {
+            let (perimeter, _area) = self.perimeter_recursive(&next, target, visited, char_count);
             total_perimeter += perimeter;
-            total_area += area;
         }
 
         (total_perimeter, *char_count)
@@ -184,12 +158,7 @@ impl Garden {
             total_sides += self.count_outside_corners(&current, target);
             total_sides += self.count_inside_corners(&current, target);
 
-            for (x, y) in [(0, 1), (1, 0), (0, -1), (-1, 0)] {
-                let next = Pos {
-                    row: current.row + x,
-                    col: current.col + y
-                };
-
+            for next in current.neighbours4() {
                 if !visited.contains(&next) && self.get(&next) == Some(*target) {
                     visited.insert(next);
                     queue.push_back(next);
@@ -229,25 +198,18 @@ impl Garden {
     }
 
     // Is the next position the same character?
-    fn is_different(&self, pos: &Pos, (x, y): (i32, i32), target: &char) -> bool {
-        let next = Pos {
-            row: pos.row + x,
-            col: pos.col + y,
-        };
-        self.get(&next) != Some(*target)
+    fn is_different(&self, pos: &Pos, direction: Direction, target: &char) -> bool {
+        self.get(&pos.next(direction)) != Some(*target)
     }
 
     fn find_total_sides(&self, target: &char) -> Vec<(u32, u32)> {
         let mut visited = HashSet::new();
         let mut regions = Vec::new();
 
-        for row in 0..self.rows {
-            for col in 0..self.cols {
-                let pos = Pos { row, col };
-                if !visited.contains(&pos) && self.get(&pos) == Some(*target) {
-                    let (sides, area) = self.analyze_corners(&pos, target, &mut visited);
-                    regions.push((sides, area));
-                }
+        for pos in self.data.find_all(target) {
+            if !visited.contains(&pos) {
+                let (sides, area) = self.analyze_corners(&pos, target, &mut visited);
+                regions.push((sides, area));
             }
         }
 
@@ -255,9 +217,52 @@ impl Garden {
     }
 }
 
-enum Direction {
-    Up,
-    Right,
-    Down,
-    Left
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+    const EXAMPLE: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+    #[test]
+    fn part_1_examples() {
+        assert_eq!(solve_part_1(&parse_input(SMALL_EXAMPLE)), 140);
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 1930);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        assert_eq!(solve_part_2(&parse_input(SMALL_EXAMPLE)), 80);
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 1206);
+    }
+
+    // same letter in two separate regions gets priced separately
+    #[test]
+    fn part_2_split_regions() {
+        let example = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+        assert_eq!(solve_part_2(&parse_input(example)), 236);
+    }
+}
@@ -0,0 +1,564 @@
+use std::fmt;
+
+pub struct Day13;
+
+impl aoc_core::Solution for Day13 {
+    type Parsed = Vec<ClawMachine>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        read_machines(input)
+    }
+
+    fn part1(&self, machines: &Self::Parsed) -> String {
+        solve_part_1(machines).to_string()
+    }
+
+    fn part2(&self, machines: &Self::Parsed) -> String {
+        solve_part_2(machines).to_string()
+    }
+}
+
+fn solve_part_1(machines: &[ClawMachine]) -> i128 {
+    total_tokens(machines, PressLimits::at_most(100))
+}
+
+fn total_tokens(machines: &[ClawMachine], limits: PressLimits) -> i128 {
+    let mut tokens = 0;
+    for machine in machines {
+        if let Some(solution) = machine.solve(limits, PUZZLE_COSTS) {
+            tokens += solution.total_cost;
+        }
+    }
+
+    tokens
+}
+
+fn read_machines(input: &str) -> Vec<ClawMachine> {
+    parse_input(input).unwrap_or_else(|err| panic!("Expected claw machines, {}", err))
+}
+
+/// Machines are blocks of three lines separated by blank lines
+///
+/// Button A: X+94, Y+34
+/// Button B: X+22, Y+67
+/// Prize: X=8400, Y=5400
+///
+/// Either of + and = works before a number and numbers can be negative
+fn parse_input(input: &str) -> Result<Vec<ClawMachine>, ParseError> {
+    let mut machines = Vec::new();
+    let mut block: Vec<(usize, &str)> = Vec::new();
+
+    // a blank line on the end flushes the last block
+    for (number, line) in input.lines().enumerate().map(|(index, line)| (index + 1, line)).chain([(0, "")]) {
+        if !line.trim().is_empty() {
+            block.push((number, line));
+            continue;
+        }
+        if block.is_empty() {
+            continue;
+        }
+
+        let [(a_line, button_a), (b_line, button_b), (prize_line, prize)] = block[..] else {
+            let (number, message) = match block.get(3) {
+                Some(&(number, _)) => (number, "expected a blank line before the next machine"),
+                None => (block[0].0, "expected Button A, Button B and Prize lines"),
+            };
+            return Err(ParseError::new(number, message));
+        };
+
+        machines.push(ClawMachine {
+            button_a: parse_line(a_line, button_a, "Button A")?,
+            button_b: parse_line(b_line, button_b, "Button B")?,
+            prize: parse_line(prize_line, prize, "Prize")?,
+        });
+        block.clear();
+    }
+
+    Ok(machines)
+}
+
+/// `Label: X+1, Y=-2`
+fn parse_line(number: usize, line: &str, label: &str) -> Result<(i64, i64), ParseError> {
+    let error = |message: String| ParseError::new(number, message);
+
+    let Some((found, coordinates)) = line.split_once(':') else {
+        return Err(error(format!("expected '{}:'", label)));
+    };
+    if found.trim() != label {
+        return Err(error(format!("expected '{}:', found '{}:'", label, found.trim())));
+    }
+
+    let [x, y] = coordinates.split(',').map(str::trim).collect::<Vec<_>>()[..] else {
+        return Err(error(String::from("expected an X and a Y separated by a comma")));
+    };
+
+    let parse_coordinate = |coordinate: &str, axis: char| {
+        let value = coordinate.strip_prefix(axis)
+            .ok_or_else(|| error(format!("expected {} first, found '{}'", axis, coordinate)))?;
+        // X+5, X=5, X-5, X=-5
+        let value = value.strip_prefix(['+', '=']).unwrap_or(value);
+        value.parse::<i64>()
+            .map_err(|_| error(format!("expected a number after {}, found '{}'", axis, value)))
+    };
+
+    Ok((parse_coordinate(x, 'X')?, parse_coordinate(y, 'Y')?))
+}
+
+// no press limit once the prizes are this far away
+fn solve_part_2(machines: &[ClawMachine]) -> i128 {
+    let machines: Vec<ClawMachine> = machines.iter()
+        .map(|machine| machine.shifted(PART_2_SHIFT).expect("Expected the prizes to still fit in an i64 once they're shifted"))
+        .collect();
+    total_tokens(&machines, PressLimits::NONE)
+}
+
+impl ClawMachine {
+    pub fn new(button_a: (i64, i64), button_b: (i64, i64), prize: (i64, i64)) -> Self {
+        ClawMachine { button_a, button_b, prize }
+    }
+
+    /// The same machine with the prize moved along both axes, None if it runs off the end of an i64
+    fn shifted(&self, PrizeShift(shift): PrizeShift) -> Option<ClawMachine> {
+        let prize = self.prize.0.checked_add(shift).zip(self.prize.1.checked_add(shift))?;
+        Some(ClawMachine { prize, ..*self })
+    }
+
+    /// Solves a * button_a + b * button_b = prize for whole, non-negative presses within the limits, with the fewest
+    /// tokens. All i128 and exact division, the prizes in part 2 are big enough that f64 starts rounding.
+    ///
+    /// When the buttons point different ways there's at most one answer, Cramer's rule. When they're on the same line
+    /// (determinant 0) there are either none or a whole line of them, see solve_collinear
+    pub fn solve(&self, limits: PressLimits, costs: ButtonCosts) -> Option<Solution> {
+        let (ax, ay) = (self.button_a.0 as i128, self.button_a.1 as i128);
+        let (bx, by) = (self.button_b.0 as i128, self.button_b.1 as i128);
+        let (px, py) = (self.prize.0 as i128, self.prize.1 as i128);
+
+        let determinant = ax * by - bx * ay;
+        if determinant == 0 {
+            return solve_collinear((ax, ay), (bx, by), (px, py), limits, costs);
+        }
+
+        let a_numerator = px * by - bx * py;
+        let b_numerator = ax * py - px * ay;
+        if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+            return None;
+        }
+
+        let a_presses = a_numerator / determinant;
+        let b_presses = b_numerator / determinant;
+        if !limits.allows(a_presses, b_presses) {
+            return None;
+        }
+
+        Some(Solution::new(a_presses, b_presses, costs))
+    }
+}
+
+/// Both buttons move the claw along the same line, so the prize has to be on it too and then only one coordinate
+/// matters: a * u + b * v = t. Extended gcd gives one answer and every other is (a + k * v/g, b - k * u/g). The token
+/// cost changes linearly with k, so the cheapest answer is at whichever end of the range of k keeps both presses
+/// between 0 and their limit
+fn solve_collinear(button_a: (i128, i128),
+                   button_b: (i128, i128),
+                   prize: (i128, i128),
+                   limits: PressLimits,
+                   costs: ButtonCosts) -> Option<Solution> {
+    let cross = |(x1, y1): (i128, i128), (x2, y2): (i128, i128)| x1 * y2 - y1 * x2;
+
+    let Some(direction) = [button_a, button_b].into_iter().find(|&button| button != (0, 0)) else {
+        // neither button does anything
+        return (prize == (0, 0) && limits.allows(0, 0)).then(|| Solution::new(0, 0, costs));
+    };
+    if cross(direction, prize) != 0 {
+        return None;
+    }
+
+    // on a vertical line every x is 0, use y instead
+    let (u, v, t) = if direction.0 != 0 {
+        (button_a.0, button_b.0, prize.0)
+    } else {
+        (button_a.1, button_b.1, prize.1)
+    };
+
+    let (g, x, y) = extended_gcd(u, v);
+    if t % g != 0 {
+        return None;
+    }
+
+    let (a0, b0) = (x * (t / g), y * (t / g));
+    let (a_step, b_step) = (v / g, -u / g);
+
+    // each press count is start + k * step and has to stay >= 0, and the limit minus it has to as well
+    let mut constraints = vec![(a0, a_step), (b0, b_step)];
+    if let Some(limit) = limits.a {
+        constraints.push((limit - a0, -a_step));
+    }
+    if let Some(limit) = limits.b {
+        constraints.push((limit - b0, -b_step));
+    }
+
+    let mut lowest: Option<i128> = None;
+    let mut highest: Option<i128> = None;
+    for (start, step) in constraints {
+        match step.signum() {
+            1 => lowest = Some(lowest.map_or(ceil_div(-start, step), |k| k.max(ceil_div(-start, step)))),
+            -1 => highest = Some(highest.map_or(floor_div(start, -step), |k| k.min(floor_div(start, -step)))),
+            _ if start < 0 => return None,
+            _ => {}
+        }
+    }
+    if let (Some(lowest), Some(highest)) = (lowest, highest) {
+        if lowest > highest {
+            return None;
+        }
+    }
+
+    let slope = costs.a as i128 * a_step + costs.b as i128 * b_step;
+    let k = if slope > 0 { lowest.or(highest) } else { highest.or(lowest) }?;
+
+    Some(Solution::new(a0 + k * a_step, b0 + k * b_step, costs))
+}
+
+/// (g, x, y) with u * x + v * y = g and g > 0, u and v can't both be 0
+fn extended_gcd(u: i128, v: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (u, v);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+// rounding towards -infinity and +infinity, / rounds towards 0. divisor is always positive here
+fn floor_div(dividend: i128, divisor: i128) -> i128 {
+    dividend.div_euclid(divisor)
+}
+
+fn ceil_div(dividend: i128, divisor: i128) -> i128 {
+    -(-dividend).div_euclid(divisor)
+}
+
+/// How far part 2 moves every prize along both axes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PrizeShift(i64);
+
+const PART_2_SHIFT: PrizeShift = PrizeShift(10_000_000_000_000);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParseError {
+    line: usize,
+    message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClawMachine {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64)
+}
+
+/// Tokens for one press of each button, costs can't be negative
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonCosts {
+    a: u32,
+    b: u32,
+}
+
+impl ButtonCosts {
+    pub const fn new(a: u32, b: u32) -> Self {
+        ButtonCosts { a, b }
+    }
+}
+
+pub const PUZZLE_COSTS: ButtonCosts = ButtonCosts::new(3, 1);
+
+/// Most presses allowed on each button, None for as many as it takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PressLimits {
+    a: Option<i128>,
+    b: Option<i128>,
+}
+
+impl PressLimits {
+    pub const NONE: PressLimits = PressLimits { a: None, b: None };
+
+    pub fn new(a: Option<i128>, b: Option<i128>) -> Self {
+        PressLimits { a, b }
+    }
+
+    /// The same limit on both buttons, part 1 allows 100
+    pub fn at_most(presses: i128) -> Self {
+        PressLimits {
+            a: Some(presses),
+            b: Some(presses),
+        }
+    }
+
+    fn allows(&self, a_presses: i128, b_presses: i128) -> bool {
+        let within = |presses: i128, limit: Option<i128>| presses >= 0 && limit.is_none_or(|limit| presses <= limit);
+        within(a_presses, self.a) && within(b_presses, self.b)
+    }
+}
+
+/// How many times to press each button and what that costs in tokens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solution {
+    pub a_presses: i128,
+    pub b_presses: i128,
+    pub total_cost: i128,
+}
+
+impl Solution {
+    fn new(a_presses: i128, b_presses: i128, costs: ButtonCosts) -> Self {
+        Solution {
+            a_presses,
+            b_presses,
+            total_cost: costs.a as i128 * a_presses + costs.b as i128 * b_presses,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Lcg;
+
+    const EXAMPLE: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&read_machines(EXAMPLE)), 480);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&read_machines(EXAMPLE)), 875318608908);
+    }
+
+    #[test]
+    fn cramer_matches_brute_force() {
+        let machines = read_machines(EXAMPLE);
+
+        for (machine, expected) in machines.iter().zip([Some((80, 40, 280)), None, Some((38, 86, 200)), None]) {
+            let brute_force = brute_force(machine, PressLimits::at_most(100), PUZZLE_COSTS);
+            assert_eq!(brute_force.map(|solution| solution.total_cost), expected.map(|(_, _, cost)| cost));
+            assert_eq!(presses(machine, PressLimits::NONE, PUZZLE_COSTS), expected);
+        }
+    }
+
+    fn machine(button_a: (i64, i64), button_b: (i64, i64), prize: (i64, i64)) -> ClawMachine {
+        ClawMachine::new(button_a, button_b, prize)
+    }
+
+    fn presses(machine: &ClawMachine, limits: PressLimits, costs: ButtonCosts) -> Option<(i128, i128, i128)> {
+        machine.solve(limits, costs).map(|solution| (solution.a_presses, solution.b_presses, solution.total_cost))
+    }
+
+    /// Tries every pair of press counts under the limits
+    fn brute_force(machine: &ClawMachine, limits: PressLimits, costs: ButtonCosts) -> Option<Solution> {
+        let (a_limit, b_limit) = (limits.a.unwrap(), limits.b.unwrap());
+        let mut best: Option<Solution> = None;
+
+        for a_presses in 0..=a_limit {
+            for b_presses in 0..=b_limit {
+                let x = a_presses * machine.button_a.0 as i128 + b_presses * machine.button_b.0 as i128;
+                let y = a_presses * machine.button_a.1 as i128 + b_presses * machine.button_b.1 as i128;
+                if (x, y) != (machine.prize.0 as i128, machine.prize.1 as i128) {
+                    continue;
+                }
+
+                let solution = Solution::new(a_presses, b_presses, costs);
+                if best.as_ref().is_none_or(|best| solution.total_cost < best.total_cost) {
+                    best = Some(solution);
+                }
+            }
+        }
+
+        best
+    }
+
+    #[test]
+    fn exact_with_huge_prizes() {
+        let (a, b) = (81_234_567_891i64, 93_456_789_123i64);
+        let big = machine((94, 34), (22, 67), (94 * a + 22 * b, 34 * a + 67 * b));
+        assert_eq!(presses(&big, PressLimits::NONE, PUZZLE_COSTS), Some((a as i128, b as i128, 3 * a as i128 + b as i128)));
+
+        // one off and there's no whole number of presses
+        let near_miss = machine((94, 34), (22, 67), (94 * a + 22 * b + 1, 34 * a + 67 * b));
+        assert_eq!(presses(&near_miss, PressLimits::NONE, PUZZLE_COSTS), None);
+    }
+
+    #[test]
+    fn collinear_buttons_pick_the_cheapest_mix() {
+        let line = machine((1, 1), (2, 2), (10, 10));
+        assert_eq!(presses(&line, PressLimits::NONE, PUZZLE_COSTS), Some((0, 5, 5)));
+        assert_eq!(presses(&line, PressLimits::NONE, ButtonCosts::new(1, 3)), Some((10, 0, 10)));
+
+        // a 3 and a 5 step to get to 13, which is 1 * 3 + 2 * 5 and nothing else
+        assert_eq!(presses(&machine((3, 6), (5, 10), (13, 26)), PressLimits::NONE, PUZZLE_COSTS), Some((1, 2, 5)));
+        // pointing opposite ways, b only undoes a
+        assert_eq!(presses(&machine((1, 1), (-1, -1), (3, 3)), PressLimits::NONE, PUZZLE_COSTS), Some((3, 0, 9)));
+        // straight up
+        assert_eq!(presses(&machine((0, 2), (0, 3), (0, 7)), PressLimits::NONE, PUZZLE_COSTS), Some((2, 1, 7)));
+    }
+
+    #[test]
+    fn press_limits() {
+        let line = machine((1, 1), (2, 2), (10, 10));
+        assert_eq!(presses(&line, PressLimits::new(None, Some(3)), PUZZLE_COSTS), Some((4, 3, 15)));
+        assert_eq!(presses(&line, PressLimits::new(Some(3), Some(3)), PUZZLE_COSTS), None);
+
+        let example = machine((94, 34), (22, 67), (8400, 5400));
+        assert_eq!(presses(&example, PressLimits::at_most(80), PUZZLE_COSTS), Some((80, 40, 280)));
+        assert_eq!(presses(&example, PressLimits::at_most(79), PUZZLE_COSTS), None);
+    }
+
+    // the closed form against trying everything, on machines small enough to try everything. Half the prizes are
+    // reachable on purpose, and a third of the machines have collinear buttons since that's where the fiddly bit is
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = Lcg::new(13);
+        let mut random = move |below: u64| rng.below(below) as i64;
+
+        for _ in 0..3000 {
+            let button_a = (random(13) - 3, random(13) - 3);
+            let button_b = if random(3) == 0 {
+                let scale = random(5) - 2;
+                (button_a.0 * scale, button_a.1 * scale)
+            } else {
+                (random(13) - 3, random(13) - 3)
+            };
+            let prize = if random(2) == 0 {
+                let (a, b) = (random(25), random(25));
+                (a * button_a.0 + b * button_b.0, a * button_a.1 + b * button_b.1)
+            } else {
+                (random(60) - 10, random(60) - 10)
+            };
+
+            let machine = machine(button_a, button_b, prize);
+            let limits = PressLimits::new(Some(random(25) as i128), Some(random(25) as i128));
+            let costs = ButtonCosts::new(random(5) as u32, random(5) as u32);
+
+            let expected = brute_force(&machine, limits, costs).map(|solution| solution.total_cost);
+            let solution = machine.solve(limits, costs);
+            assert_eq!(solution.as_ref().map(|solution| solution.total_cost), expected, "{:?} {:?} {:?}", machine, limits, costs);
+
+            // and the presses it picked really land on the prize
+            if let Some(solution) = solution {
+                assert!(limits.allows(solution.a_presses, solution.b_presses));
+                let x = solution.a_presses * button_a.0 as i128 + solution.b_presses * button_b.0 as i128;
+                let y = solution.a_presses * button_a.1 as i128 + solution.b_presses * button_b.1 as i128;
+                assert_eq!((x, y), (prize.0 as i128, prize.1 as i128));
+            }
+        }
+    }
+
+    #[test]
+    fn collinear_buttons_without_an_answer() {
+        // steps of 2 never land on 5
+        assert_eq!(presses(&machine((2, 2), (4, 4), (5, 5)), PressLimits::NONE, PUZZLE_COSTS), None);
+        // the prize is off the line
+        assert_eq!(presses(&machine((1, 1), (2, 2), (10, 11)), PressLimits::NONE, PUZZLE_COSTS), None);
+        // 3 and 5 can't make 7 without going backwards
+        assert_eq!(presses(&machine((3, 3), (5, 5), (7, 7)), PressLimits::NONE, PUZZLE_COSTS), None);
+        // both buttons are broken
+        assert_eq!(presses(&machine((0, 0), (0, 0), (1, 0)), PressLimits::NONE, PUZZLE_COSTS), None);
+        assert_eq!(presses(&machine((0, 0), (0, 0), (0, 0)), PressLimits::NONE, PUZZLE_COSTS), Some((0, 0, 0)));
+    }
+
+    #[test]
+    fn parses_any_line_endings_and_spacing() {
+        let expected = parse_input(EXAMPLE).unwrap();
+        assert_eq!(expected.len(), 4);
+
+        for input in [
+            EXAMPLE.replace('\n', "\r\n"),
+            EXAMPLE.trim_end().to_string(),
+            EXAMPLE.replace("\n\n", "\n\n\n"),
+            format!("\n\n{}\n\n", EXAMPLE),
+        ] {
+            assert_eq!(parse_input(&input).as_ref(), Ok(&expected), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn parses_equals_signs_and_negatives() {
+        let input = "\
+Button A: X=94, Y-34
+Button B: X+-22, Y=+67
+Prize: X=-8400, Y=5400
+";
+        assert_eq!(parse_input(input), Ok(vec![machine((94, -34), (-22, 67), (-8400, 5400))]));
+    }
+
+    #[test]
+    fn shifting_moves_the_prize() {
+        let example = machine((94, -34), (-22, 67), (-8400, 5400));
+        assert_eq!(example.shifted(PrizeShift(10)), Some(machine((94, -34), (-22, 67), (-8390, 5410))));
+
+        // too far away once it's shifted
+        assert_eq!(machine((1, 1), (1, 1), (1, i64::MAX)).shifted(PART_2_SHIFT), None);
+    }
+
+    #[test]
+    fn errors_point_at_the_line() {
+        let error = |input: &str| parse_input(input).unwrap_err().to_string();
+        let second_machine = |from: &str, to: &str| EXAMPLE.replacen(from, to, 1);
+
+        assert_eq!(error(&second_machine("Prize: X=12748", "Prize: X=12748Q")),
+                   "line 7: expected a number after X, found '12748Q'");
+        assert_eq!(error(&second_machine("Button B: X+67", "Button C: X+67")),
+                   "line 6: expected 'Button B:', found 'Button C:'");
+        assert_eq!(error(&second_machine("Prize: X=12748, Y=12176\n", "")),
+                   "line 5: expected Button A, Button B and Prize lines");
+        assert_eq!(error(&second_machine("Prize: X=12748, Y=12176\n\n", "Prize: X=12748, Y=12176\n")),
+                   "line 8: expected a blank line before the next machine");
+        assert_eq!(error(&second_machine("X+26, Y+66", "Y+66, X+26")),
+                   "line 5: expected X first, found 'Y+66'");
+        assert_eq!(error(&second_machine("X+26, Y+66", "X+26 Y+66")),
+                   "line 5: expected an X and a Y separated by a comma");
+    }
+}
@@ -0,0 +1,163 @@
+//! Drawing the robots out to image files, so thousands of seconds can be flicked through in an image viewer instead
+//! of scrolled past in a terminal. The more robots on a tile the brighter it is
+
+use crate::{image, Bathroom};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameFormat {
+    /// One binary PGM per second
+    Pgm,
+    /// One PNG per second
+    Png,
+    /// A single animated GIF, a tenth of a second a frame
+    Gif,
+    /// Every frame tiled into one PNG, left to right then down
+    Sheet,
+}
+
+impl FrameFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "pgm" => Some(FrameFormat::Pgm),
+            "png" => Some(FrameFormat::Png),
+            "gif" => Some(FrameFormat::Gif),
+            "sheet" => Some(FrameFormat::Sheet),
+            _ => None,
+        }
+    }
+}
+
+/// hundredths of a second
+const GIF_DELAY: u16 = 10;
+/// lines between the frames on a sheet, so robots on the edges of two frames don't run together
+const SHEET_GAP: u8 = 64;
+
+/// File names and what goes in them
+pub(crate) fn render(bathroom: &Bathroom, seconds: &[i64], format: FrameFormat) -> Vec<(String, Vec<u8>)> {
+    let (width, height) = (bathroom.width as usize, bathroom.height as usize);
+    let frames = frames(bathroom, seconds);
+
+    match format {
+        FrameFormat::Pgm => seconds.iter()
+            .zip(&frames)
+            .map(|(seconds, frame)| (format!("frame_{:05}.pgm", seconds), image::pgm(width, height, frame)))
+            .collect(),
+        FrameFormat::Png => seconds.iter()
+            .zip(&frames)
+            .map(|(seconds, frame)| (format!("frame_{:05}.png", seconds), image::png(width, height, frame)))
+            .collect(),
+        FrameFormat::Gif => vec![(String::from("frames.gif"), image::gif(width, height, &frames, GIF_DELAY))],
+        FrameFormat::Sheet => {
+            let (sheet_width, sheet_height, pixels) = sheet(width, height, &frames);
+            vec![(String::from("sheet.png"), image::png(sheet_width, sheet_height, &pixels))]
+        }
+    }
+}
+
+/// Greyscale pixels for each second. Every frame shares one scale so the same brightness means the same number of
+/// robots all the way through
+fn frames(bathroom: &Bathroom, seconds: &[i64]) -> Vec<Vec<u8>> {
+    let densities = seconds.iter()
+        .map(|&seconds| bathroom.density_at(seconds))
+        .collect::<Vec<_>>();
+    let busiest = densities.iter().flatten().copied().max().unwrap_or(0);
+
+    densities.iter()
+        .map(|density| density.iter().map(|&robots| brightness(robots, busiest)).collect())
+        .collect()
+}
+
+/// A lone robot is half way to white and the busiest tile is white, a lone robot on a dim linear scale is too easy
+/// to miss
+fn brightness(robots: u32, busiest: u32) -> u8 {
+    match robots {
+        0 => 0,
+        _ if busiest <= 1 => 255,
+        _ => (128 + 127 * (robots - 1) / (busiest - 1)) as u8,
+    }
+}
+
+/// Frames in a roughly square grid with a line around each one
+fn sheet(width: usize, height: usize, frames: &[Vec<u8>]) -> (usize, usize, Vec<u8>) {
+    let columns = (1..).find(|columns| columns * columns >= frames.len()).unwrap();
+    let rows = frames.len().div_ceil(columns);
+    let sheet_width = columns * (width + 1) + 1;
+    let sheet_height = rows * (height + 1) + 1;
+
+    let mut pixels = vec![SHEET_GAP; sheet_width * sheet_height];
+    for (index, frame) in frames.iter().enumerate() {
+        let left = index % columns * (width + 1) + 1;
+        let top = index / columns * (height + 1) + 1;
+
+        for (row, line) in frame.chunks(width).enumerate() {
+            let start = (top + row) * sheet_width + left;
+            pixels[start..start + width].copy_from_slice(line);
+        }
+    }
+
+    (sheet_width, sheet_height, pixels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input;
+
+    #[test]
+    fn brighter_where_robots_pile_up() {
+        // two robots meet at 2,1 after a second, the third sits still at 0,0
+        let bathroom = parse_input("p=1,1 v=1,0\np=3,1 v=-1,0\np=0,0 v=0,0", 5, 3);
+        let frames = frames(&bathroom, &[0, 1]);
+
+        assert_eq!(frames[0], [
+            128, 0, 0, 0, 0,
+            0, 128, 0, 128, 0,
+            0, 0, 0, 0, 0,
+        ]);
+        assert_eq!(frames[1], [
+            128, 0, 0, 0, 0,
+            0, 0, 255, 0, 0,
+            0, 0, 0, 0, 0,
+        ]);
+    }
+
+    #[test]
+    fn brightness_scale() {
+        assert_eq!(brightness(0, 0), 0);
+        assert_eq!(brightness(1, 1), 255);
+        assert_eq!(brightness(1, 3), 128);
+        assert_eq!(brightness(2, 3), 191);
+        assert_eq!(brightness(3, 3), 255);
+    }
+
+    #[test]
+    fn sheet_layout() {
+        let frames = (1..=5).map(|n| vec![n; 2 * 3]).collect::<Vec<_>>();
+        let (width, height, pixels) = sheet(3, 2, &frames);
+
+        // 3 across and 2 down with a line around each
+        assert_eq!((width, height), (3 * 4 + 1, 2 * 3 + 1));
+        let row = |y: usize| &pixels[y * width..(y + 1) * width];
+        assert_eq!(row(0), [SHEET_GAP; 13]);
+        assert_eq!(row(1), [SHEET_GAP, 1, 1, 1, SHEET_GAP, 2, 2, 2, SHEET_GAP, 3, 3, 3, SHEET_GAP]);
+        assert_eq!(row(4), [SHEET_GAP, 4, 4, 4, SHEET_GAP, 5, 5, 5, SHEET_GAP, SHEET_GAP, SHEET_GAP, SHEET_GAP, SHEET_GAP]);
+    }
+
+    #[test]
+    fn file_names() {
+        let bathroom = parse_input("p=0,0 v=1,1", 11, 7);
+
+        let names = |format| render(&bathroom, &[0, 81, 12345], format).into_iter().map(|(name, _)| name).collect::<Vec<_>>();
+        assert_eq!(names(FrameFormat::Png), ["frame_00000.png", "frame_00081.png", "frame_12345.png"]);
+        assert_eq!(names(FrameFormat::Pgm), ["frame_00000.pgm", "frame_00081.pgm", "frame_12345.pgm"]);
+        assert_eq!(names(FrameFormat::Gif), ["frames.gif"]);
+        assert_eq!(names(FrameFormat::Sheet), ["sheet.png"]);
+    }
+
+    #[test]
+    fn format_names() {
+        assert_eq!(FrameFormat::from_name("sheet"), Some(FrameFormat::Sheet));
+        assert_eq!(FrameFormat::from_name("gif"), Some(FrameFormat::Gif));
+        assert_eq!(FrameFormat::from_name("jpeg"), None);
+    }
+}
@@ -0,0 +1,325 @@
+//! Just enough of PGM, PNG and GIF to write greyscale frames without pulling in an image crate. Nothing gets
+//! compressed, PNG uses stored deflate blocks and GIF clears its code table before it can grow, so files are about a
+//! byte a pixel. Pixels are a row at a time, one byte each, 0 black and 255 white
+
+/// Binary PGM, a tiny header and then the pixels
+pub(crate) fn pgm(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    let mut pgm = format!("P5\n{} {}\n255\n", width, height).into_bytes();
+    pgm.extend_from_slice(pixels);
+    pgm
+}
+
+pub(crate) fn png(width: usize, height: usize, pixels: &[u8]) -> Vec<u8> {
+    // every row starts with its filter type, 0 is none
+    let mut rows = Vec::with_capacity((width + 1) * height);
+    for row in pixels.chunks(width.max(1)) {
+        rows.push(0);
+        rows.extend_from_slice(row);
+    }
+
+    let mut header = Vec::new();
+    header.extend((width as u32).to_be_bytes());
+    header.extend((height as u32).to_be_bytes());
+    // 8 bit greyscale, default compression, filtering and no interlacing
+    header.extend([8, 0, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &header);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&rows));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    let mut checked = kind.to_vec();
+    checked.extend_from_slice(data);
+
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend_from_slice(&checked);
+    png.extend(crc32(&checked).to_be_bytes());
+}
+
+/// A zlib stream of uncompressed deflate blocks, those top out at 65535 bytes each
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+
+    let blocks = data.chunks(65535).collect::<Vec<_>>();
+    if blocks.is_empty() {
+        zlib.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    for (index, block) in blocks.iter().enumerate() {
+        let last = index == blocks.len() - 1;
+        let length = block.len() as u16;
+
+        zlib.push(last as u8);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+
+    zlib.extend(adler32(data).to_be_bytes());
+    zlib
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut byte = 0;
+    while byte < 256 {
+        let mut crc = byte as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 { 0xedb88320 ^ (crc >> 1) } else { crc >> 1 };
+            bit += 1;
+        }
+        table[byte] = crc;
+        byte += 1;
+    }
+    table
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| CRC_TABLE[((crc ^ byte as u32) & 0xff) as usize] ^ (crc >> 8))
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (a, b) = bytes.iter().fold((1, 0), |(a, b), &byte| {
+        let a = (a + byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    (b << 16) | a
+}
+
+/// Loops forever, `delay` is in hundredths of a second
+pub(crate) fn gif(width: usize, height: usize, frames: &[Vec<u8>], delay: u16) -> Vec<u8> {
+    let width = u16::try_from(width).expect("Expected a GIF to fit in 65535 pixels across");
+    let height = u16::try_from(height).expect("Expected a GIF to fit in 65535 pixels down");
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(width.to_le_bytes());
+    gif.extend(height.to_le_bytes());
+    // a global colour table of 256 entries, no background or aspect ratio
+    gif.extend([0xf7, 0, 0]);
+    for grey in 0..=255 {
+        gif.extend([grey, grey, grey]);
+    }
+    gif.extend([0x21, 0xff, 0x0b]);
+    gif.extend(b"NETSCAPE2.0");
+    gif.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    for frame in frames {
+        gif.extend([0x21, 0xf9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00]);
+
+        gif.extend([0x2c, 0, 0, 0, 0]);
+        gif.extend(width.to_le_bytes());
+        gif.extend(height.to_le_bytes());
+        gif.push(0);
+
+        gif.push(8);
+        for block in lzw_uncompressed(frame).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0);
+    }
+
+    gif.push(0x3b);
+    gif
+}
+
+const CLEAR_CODE: u32 = 256;
+const END_CODE: u32 = 257;
+/// Every code after the first one following a clear adds a table entry, and 254 of them would take the table to 512
+/// where codes go up to 10 bits. Clearing a bit before that keeps every code 9 bits and each one a pixel
+const PIXELS_PER_CLEAR: usize = 250;
+
+fn lzw_uncompressed(pixels: &[u8]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+
+    bits.write(CLEAR_CODE);
+    for (index, &pixel) in pixels.iter().enumerate() {
+        if index > 0 && index.is_multiple_of(PIXELS_PER_CLEAR) {
+            bits.write(CLEAR_CODE);
+        }
+        bits.write(pixel as u32);
+    }
+    bits.write(END_CODE);
+
+    bits.finish()
+}
+
+/// 9 bit codes packed least significant bit first
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u32) {
+        self.buffer |= code << self.count;
+        self.count += 9;
+        while self.count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gradient(width: usize, height: usize) -> Vec<u8> {
+        (0..width * height).map(|index| (index * 7 % 256) as u8).collect()
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"123456789"), 0xcbf43926);
+        assert_eq!(crc32(b"IEND"), 0xae426082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e60398);
+        assert_eq!(adler32(b""), 1);
+    }
+
+    #[test]
+    fn pgm_header() {
+        assert_eq!(pgm(3, 2, &[0, 1, 2, 3, 4, 255]), b"P5\n3 2\n255\n\x00\x01\x02\x03\x04\xff");
+    }
+
+    /// Walks the chunks checking their CRCs and unpacks the stored blocks back into pixels
+    fn read_png(png: &[u8]) -> (usize, usize, Vec<u8>) {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+
+        let mut chunks = Vec::new();
+        let mut at = 8;
+        while at < png.len() {
+            let length = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+            let checked = &png[at + 4..at + 8 + length];
+            let crc = u32::from_be_bytes(png[at + 8 + length..at + 12 + length].try_into().unwrap());
+            assert_eq!(crc32(checked), crc);
+            chunks.push((&checked[..4], &checked[4..]));
+            at += 12 + length;
+        }
+        assert_eq!(chunks.iter().map(|(kind, _)| *kind).collect::<Vec<_>>(), [b"IHDR", b"IDAT", b"IEND"]);
+
+        let header = chunks[0].1;
+        let width = u32::from_be_bytes(header[0..4].try_into().unwrap()) as usize;
+        let height = u32::from_be_bytes(header[4..8].try_into().unwrap()) as usize;
+
+        let zlib = chunks[1].1;
+        let mut rows = Vec::new();
+        let mut at = 2;
+        loop {
+            let last = zlib[at] == 1;
+            let length = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]);
+            assert_eq!(!length, u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]));
+            rows.extend_from_slice(&zlib[at + 5..at + 5 + length as usize]);
+            at += 5 + length as usize;
+            if last {
+                break;
+            }
+        }
+        assert_eq!(&zlib[at..], adler32(&rows).to_be_bytes());
+
+        let pixels = rows.chunks(width + 1)
+            .flat_map(|row| {
+                assert_eq!(row[0], 0);
+                row[1..].to_vec()
+            })
+            .collect();
+        (width, height, pixels)
+    }
+
+    #[test]
+    fn png_round_trip() {
+        // big enough to need a few deflate blocks
+        for (width, height) in [(3, 2), (101, 103), (400, 300)] {
+            let pixels = gradient(width, height);
+            assert_eq!(read_png(&png(width, height, &pixels)), (width, height, pixels));
+        }
+    }
+
+    /// A plain GIF LZW decoder that grows its codes like any viewer would
+    fn decode_lzw(data: &[u8]) -> Vec<u8> {
+        let mut pixels = Vec::new();
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+        let (mut width, mut buffer, mut count) = (9, 0u32, 0);
+        let mut bytes = data.iter();
+
+        loop {
+            while count < width {
+                buffer |= (*bytes.next().expect("ran out before the end code") as u32) << count;
+                count += 8;
+            }
+            let code = buffer & ((1 << width) - 1);
+            buffer >>= width;
+            count -= width;
+
+            if code == CLEAR_CODE {
+                table = (0..=255).map(|pixel| vec![pixel]).chain([vec![], vec![]]).collect();
+                previous = None;
+                width = 9;
+                continue;
+            }
+            if code == END_CODE {
+                return pixels;
+            }
+
+            let entry = match (table.get(code as usize), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("code {} before anything to build it from", code),
+            };
+            if let Some(previous) = previous {
+                table.push([previous, vec![entry[0]]].concat());
+                if table.len() == 1 << width && width < 12 {
+                    width += 1;
+                }
+            }
+            pixels.extend_from_slice(&entry);
+            previous = Some(entry);
+        }
+    }
+
+    #[test]
+    fn gif_frames_decode() {
+        let frames = [gradient(101, 103), vec![0; 101 * 103], vec![255; 101 * 103]];
+        let gif = gif(101, 103, &frames, 10);
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // each frame's data follows its image descriptor and the code size byte
+        let mut at = 13 + 256 * 3 + 19;
+        for frame in &frames {
+            assert_eq!(&gif[at..at + 4], [0x21, 0xf9, 0x04, 0x00]);
+            assert_eq!(gif[at + 8 + 10], 8);
+            at += 8 + 11;
+
+            let mut data = Vec::new();
+            while gif[at] != 0 {
+                let length = gif[at] as usize;
+                data.extend_from_slice(&gif[at + 1..at + 1 + length]);
+                at += 1 + length;
+            }
+            at += 1;
+
+            assert_eq!(&decode_lzw(&data), frame);
+        }
+        assert_eq!(at, gif.len() - 1);
+    }
+}
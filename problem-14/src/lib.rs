@@ -0,0 +1,320 @@
+use aoc_core::Solution;
+use regex::Regex;
+
+pub use frames::FrameFormat;
+
+mod frames;
+mod image;
+
+// the example in the puzzle uses a smaller 11 wide 7 tall bathroom
+const BATHROOM_WIDTH: i32 = 101;
+const BATHROOM_HEIGHT: i32 = 103;
+
+/// Every robot is back where it started after this many seconds, so frames further apart than this repeat
+pub const CYCLE_SECONDS: i64 = BATHROOM_WIDTH as i64 * BATHROOM_HEIGHT as i64;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed = Bathroom;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input, BATHROOM_WIDTH, BATHROOM_HEIGHT)
+    }
+
+    fn part1(&self, bathroom: &Self::Parsed) -> String {
+        solve_part_1(bathroom).to_string()
+    }
+
+    fn part2(&self, bathroom: &Self::Parsed) -> String {
+        solve_part_2(bathroom).to_string()
+    }
+}
+
+/// The robots at each of `seconds` as image files named for what they hold, see FrameFormat
+pub fn robot_frames(input: &str, seconds: &[i64], format: FrameFormat) -> Vec<(String, Vec<u8>)> {
+    frames::render(&parse_input(input, BATHROOM_WIDTH, BATHROOM_HEIGHT), seconds, format)
+}
+
+fn solve_part_1(bathroom: &Bathroom) -> usize {
+    let mut bathroom = bathroom.clone();
+
+    let seconds = 100;
+    bathroom.update_robots(seconds);
+
+    bathroom.get_robot_count()
+}
+
+// can't work the size out from the robots, there's no promise one of them starts on the far wall
+fn parse_input(input: &str, width: i32, height: i32) -> Bathroom {
+    let mut robots = Vec::new();
+    let re = Regex::new(r"-?\d+").unwrap();
+
+    for line in input.lines().filter(|line| !line.trim().is_empty()) {
+        let numbers = re.find_iter(line)
+            .map(|m| m.as_str().parse::<i32>().expect("Expected robot numbers to fit in an i32"))
+            .collect::<Vec<i32>>();
+        let [px, py, vx, vy] = numbers[..] else {
+            panic!("Expected a robot like p=0,4 v=3,-3, got '{}'", line);
+        };
+
+        let pos = Pos {
+            x: py,
+            y: px,
+        };
+        let velocity = Velocity {
+            vertical: vy,
+            horizontal: vx
+        };
+
+        let robot = Robot {
+            pos,
+            velocity
+        };
+
+        robots.push(robot);
+    }
+
+    Bathroom::new(height, width, robots)
+}
+
+/// The first second the robots draw the tree, `aoc frames --seconds <answer> --output <dir>` to see it
+fn solve_part_2(bathroom: &Bathroom) -> i64 {
+    bathroom.find_tree()
+        .expect("Expected the robots to bunch up into a tree at some point")
+}
+
+/// How spread out some values are, the variance times count squared so it stays a whole number. Only ever compared
+/// against other spreads of the same number of values
+fn spread(values: impl Iterator<Item = i64>) -> i64 {
+    let (count, sum, sum_of_squares) = values.fold((0, 0, 0), |(count, sum, squares), value| {
+        (count + 1, sum + value, squares + value * value)
+    });
+
+    count * sum_of_squares - sum * sum
+}
+
+/// The first t >= 0 with t % first_period == first and t % second_period == second, stepping through the first
+/// period's matches until one fits the second. None if the periods share a factor and the two never line up
+fn chinese_remainder(first: i64, first_period: i64, second: i64, second_period: i64) -> Option<i64> {
+    (0..second_period)
+        .map(|step| first + step * first_period)
+        .find(|t| t.rem_euclid(second_period) == second)
+}
+
+#[derive(Debug, Clone)]
+pub struct Bathroom {
+    height: i32,
+    width: i32,
+    robots: Vec<Robot>
+}
+
+impl Bathroom {
+    fn new(height: i32, width: i32, robots: Vec<Robot>) -> Self {
+        Bathroom {
+            height,
+            width,
+            robots
+        }
+    }
+
+    /// Robots move in straight lines and wrap, so jumping ahead is one multiply and a mod
+    fn update_robots(&mut self, seconds: i64) {
+        for robot in self.robots.iter_mut() {
+            robot.pos = robot.position_at(seconds, self.height, self.width);
+        }
+    }
+
+    /// Columns repeat every width seconds and rows every height seconds, each on their own. The tree is a tight bunch
+    /// of robots, so the second in the first width where the columns are least spread out and the second in the first
+    /// height where the rows are pin it down, and the Chinese remainder theorem puts them together into the first
+    /// second both happen
+    fn find_tree(&self) -> Option<i64> {
+        let (width, height) = (self.width as i64, self.height as i64);
+
+        let column_time = (0..width).min_by_key(|&seconds| {
+            spread(self.robots.iter().map(|robot| (robot.pos.y as i64 + robot.velocity.horizontal as i64 * seconds).rem_euclid(width)))
+        })?;
+        let row_time = (0..height).min_by_key(|&seconds| {
+            spread(self.robots.iter().map(|robot| (robot.pos.x as i64 + robot.velocity.vertical as i64 * seconds).rem_euclid(height)))
+        })?;
+
+        chinese_remainder(column_time, width, row_time, height)
+    }
+
+    fn get_robot_count(&self) -> usize {
+        let middle_row = self.height / 2;
+        let middle_col = self.width / 2;
+
+        // flex those quads
+        let mut top_left_quad = 0;
+        let mut top_right_quad = 0;
+        let mut bottom_left_quad = 0;
+        let mut bottom_right_quad = 0;
+
+        for robot in &self.robots {
+            // Exclude middle row and column
+            if robot.pos.x == middle_row || robot.pos.y == middle_col {
+                continue;
+            }
+
+            match (robot.pos.x < middle_row, robot.pos.y < middle_col) {
+                (true, true) => top_left_quad += 1,
+                (true, false) => top_right_quad += 1,
+                (false, true) => bottom_left_quad += 1,
+                (false, false) => bottom_right_quad += 1,
+            }
+        }
+
+        top_left_quad * top_right_quad * bottom_left_quad * bottom_right_quad
+    }
+
+    /// How many robots are on each tile `seconds` from now, a row at a time
+    fn density_at(&self, seconds: i64) -> Vec<u32> {
+        let mut density = vec![0; (self.width * self.height) as usize];
+        for robot in &self.robots {
+            let pos = robot.position_at(seconds, self.height, self.width);
+            density[(pos.x * self.width + pos.y) as usize] += 1;
+        }
+
+        density
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Robot {
+    pos: Pos,
+    velocity: Velocity
+}
+
+impl Robot {
+    /// Where the robot is `seconds` from now, i64 so a few thousand seconds of fast robots can't overflow
+    fn position_at(&self, seconds: i64, height: i32, width: i32) -> Pos {
+        let x = self.pos.x as i64 + self.velocity.vertical as i64 * seconds;
+        let y = self.pos.y as i64 + self.velocity.horizontal as i64 * seconds;
+
+        Pos {
+            x: x.rem_euclid(height as i64) as i32,
+            y: y.rem_euclid(width as i64) as i32,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Pos {
+    x: i32,
+    y: i32
+}
+
+#[derive(Debug, Clone)]
+struct Velocity {
+    vertical: i32,
+    horizontal: i32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Lcg;
+
+    const EXAMPLE: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE, 11, 7)), 12);
+    }
+
+    #[test]
+    fn blank_lines_are_skipped() {
+        let bathroom = parse_input("\np=2,4 v=2,-3\n\np=0,0 v=1,1\n\n", 11, 7);
+        assert_eq!(bathroom.robots.len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "Expected a robot like p=0,4 v=3,-3, got 'p=2,4 v=2'")]
+    fn short_robot_line() {
+        parse_input("p=2,4 v=2", 11, 7);
+    }
+
+    // the single robot walkthrough from the puzzle
+    #[test]
+    fn robot_wraps_around() {
+        let mut bathroom = parse_input("p=2,4 v=2,-3", 11, 7);
+        bathroom.update_robots(5);
+
+        assert_eq!((bathroom.robots[0].pos.y, bathroom.robots[0].pos.x), (1, 3));
+    }
+
+    #[test]
+    fn chinese_remainder_examples() {
+        assert_eq!(chinese_remainder(2, 3, 3, 5), Some(8));
+        assert_eq!(chinese_remainder(0, 101, 0, 103), Some(0));
+        assert_eq!(chinese_remainder(100, 101, 102, 103), Some(101 * 103 - 1));
+        assert_eq!(chinese_remainder(1, 4, 2, 6), None);
+    }
+
+    #[test]
+    fn jumping_matches_stepping() {
+        let mut jumped = parse_input(EXAMPLE, 11, 7);
+        jumped.update_robots(1234);
+
+        let mut stepped = parse_input(EXAMPLE, 11, 7);
+        for _ in 0..1234 {
+            stepped.update_robots(1);
+        }
+
+        let positions = |bathroom: &Bathroom| bathroom.robots.iter().map(|robot| (robot.pos.x, robot.pos.y)).collect::<Vec<_>>();
+        assert_eq!(positions(&jumped), positions(&stepped));
+    }
+
+    /// A tree drawn at `seconds` with some robots wandering about elsewhere, wound back to where they'd all start
+    fn hidden_tree(seconds: i64) -> String {
+        let mut rng = Lcg::new(14);
+        let mut random = move |below: i64| rng.below(below as u64) as i64;
+
+        let (width, height) = (BATHROOM_WIDTH as i64, BATHROOM_HEIGHT as i64);
+        let mut robots_at_tree = Vec::new();
+        // a triangle of a tree with a trunk, off to one side like the real one
+        for row in 0..20 {
+            for column in -row..=row {
+                robots_at_tree.push((60 + column, 30 + row));
+            }
+        }
+        for row in 20..24 {
+            robots_at_tree.extend([(59, 30 + row), (60, 30 + row), (61, 30 + row)]);
+        }
+        for _ in 0..250 {
+            robots_at_tree.push((random(width), random(height)));
+        }
+
+        robots_at_tree.into_iter()
+            .map(|(column, row)| {
+                let (horizontal, vertical) = (random(201) - 100, random(201) - 100);
+                let start_column = (column - horizontal * seconds).rem_euclid(width);
+                let start_row = (row - vertical * seconds).rem_euclid(height);
+                format!("p={},{} v={},{}", start_column, start_row, horizontal, vertical)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn finds_the_tree() {
+        for seconds in [0, 81, 6789, 101 * 103 - 1] {
+            assert_eq!(solve_part_2(&parse_input(&hidden_tree(seconds), BATHROOM_WIDTH, BATHROOM_HEIGHT)), seconds);
+        }
+    }
+}
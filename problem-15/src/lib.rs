@@ -0,0 +1,395 @@
+use aoc_core::{sections, Solution};
+use aoc_grid::{Direction, Grid, Pos};
+
+const ROBOT_CHAR: char = '@';
+const WALL: char = '#';
+const BOX_CHAR: char = 'O';
+const BOX_LEFT: char = '[';
+const BOX_RIGHT: char = ']';
+const EMPTY_SPACE:char = '.';
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed = Warehouse;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, warehouse: &Self::Parsed) -> String {
+        solve_part_1(warehouse).to_string()
+    }
+
+    fn part2(&self, warehouse: &Self::Parsed) -> String {
+        solve_part_2(warehouse).to_string()
+    }
+}
+
+// warehouse map first, then a blank line, then the robot moves (which can wrap over several lines)
+fn split_input(input: &str) -> (&str, &str) {
+    let [warehouse, moves] = sections(input)[..] else {
+        panic!("Expected the warehouse map and robot moves separated by a blank line");
+    };
+
+    (warehouse, moves)
+}
+
+fn solve_part_1(warehouse: &Warehouse) -> usize {
+    let mut warehouse = warehouse.clone();
+
+    // look into the borrow mechanics here if we don't clone
+    for robot_move in warehouse.robot.moves.clone() {
+        let current_position = warehouse.robot.position;
+        if let Some(open_space) = warehouse.get_next_open_spot(&current_position, &robot_move) {
+            warehouse.move_boxes(&current_position, &open_space, &robot_move);
+        }
+    }
+
+    warehouse.gps_sum()
+}
+
+fn parse_input(input: &str) -> Warehouse {
+    let (warehouse, moves) = split_input(input);
+    new_warehouse(Grid::parse(warehouse), parse_moves(moves))
+}
+
+fn new_warehouse(grid: Grid<char>, moves: Vec<Direction>) -> Warehouse {
+    let robot = Robot {
+        position: grid.find(&ROBOT_CHAR).expect("Expected the robot '@' somewhere in the warehouse"),
+        moves
+    };
+
+    Warehouse {
+        grid,
+        robot,
+    }
+}
+
+fn widen(warehouse: &Warehouse) -> Warehouse {
+    // everything except the robot is twice as wide, so widen the text and parse that
+    let wide_input: String = warehouse.grid.rows()
+        .map(|line| {
+            let mut row = String::new();
+            for &character in line {
+                match character {
+                    c if c == WALL => row.push_str("##"),
+                    c if c == BOX_CHAR => row.push_str("[]"),
+                    c if c == ROBOT_CHAR => row.push_str("@."),
+                    c if c == EMPTY_SPACE => row.push_str(".."),
+                    _ => {}
+                };
+            }
+            row + "\n"
+        })
+        .collect();
+
+    new_warehouse(Grid::parse(&wide_input), warehouse.robot.moves.clone())
+}
+
+fn parse_moves(moves: &str) -> Vec<Direction> {
+    moves.lines()
+        .flat_map(|line| {
+            line.chars().map(|mov| {
+                Direction::from_arrow(mov)
+                    .unwrap_or_else(|| panic!("Invalid direction: {}", mov))
+            })
+        })
+        .collect::<Vec<Direction>>()
+}
+
+fn solve_part_2(warehouse: &Warehouse) -> i32 {
+    let mut warehouse = widen(warehouse);
+
+    // look into the borrow mechanics here if we don't clone
+    for robot_move in warehouse.robot.moves.clone() {
+        let current_position = warehouse.robot.position;
+            if let Some(current_connected_boxes) = warehouse.get_connected_boxes(&current_position, &robot_move) {
+                let updated_boxes = update_boxes(&current_connected_boxes, &robot_move);
+                warehouse.move_boxes_wide(&current_connected_boxes, &updated_boxes, &robot_move);
+            }
+    }
+
+    warehouse.gps_sum_wide()
+}
+
+fn update_boxes(boxes: &Vec<Vec<PosChar>>, direction: &Direction) -> Vec<Vec<PosChar>> {
+    let mut updated_boxes = Vec::<Vec<PosChar>>::new();
+
+    for positions in boxes {
+        let mut updated_row = Vec::<PosChar>::new();
+        for position in positions {
+            let mut copied_position = *position;
+            copied_position.pos = copied_position.pos.next(*direction);
+            updated_row.push(copied_position);
+        }
+        updated_boxes.push(updated_row);
+    }
+
+    updated_boxes
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    grid: Grid<char>,
+    robot: Robot,
+}
+
+impl Warehouse {
+    fn gps_sum(&self) -> usize {
+        self.grid.find_all(&BOX_CHAR)
+            .map(|pos| (pos.row as usize * 100) + pos.col as usize)
+            .sum()
+    }
+
+    fn gps_sum_wide(&self) -> i32 {
+        self.grid.find_all(&BOX_LEFT)
+            .map(|pos| (100 * pos.row) + pos.col)
+            .sum()
+    }
+
+    fn get_next_open_spot(&self, pos: &Pos, direction: &Direction) -> Option<Pos> {
+        let mut next_pos = pos.next(*direction);
+        let mut grid_object = self.grid[next_pos];
+
+        while grid_object != WALL && grid_object != EMPTY_SPACE {
+            next_pos = next_pos.next(*direction);
+            grid_object = self.grid[next_pos];
+        }
+
+        if grid_object == EMPTY_SPACE {
+            return Some(next_pos);
+        }
+
+        None
+    }
+
+    /// start_pos is where the robot is
+    /// end_pos is where the next available spot is
+    fn move_boxes(&mut self, start_pos: &Pos, open_space: &Pos, direction: &Direction) {
+        // set the start_pos to '.' as that's where the robot used to be
+        self.grid[*start_pos] = '.';
+
+        // set the pos right after that to '@' as that's where the robot is now, more purely for graphical debugging
+        let mut next_pos = start_pos.next(*direction);
+        self.robot.update_pos(&next_pos);
+        self.grid[next_pos] = '@';
+
+        // then finish the rest up
+        let end_pos = open_space.next(*direction);
+        next_pos = next_pos.next(*direction);
+        while next_pos != end_pos {
+            self.grid[next_pos] = 'O';
+            next_pos = next_pos.next(*direction);
+        }
+    }
+
+    fn move_boxes_wide(&mut self, old_positions: &Vec<Vec<PosChar>>, boxes_and_space: &Vec<Vec<PosChar>>, direction: &Direction) {
+        // Clear the robot's old position
+        self.grid[self.robot.position] = EMPTY_SPACE;
+
+        // Single space movement case
+        if boxes_and_space.len() == 1 && boxes_and_space[0].len() == 1 {
+            let new_pos = self.robot.position.next(*direction);
+            self.robot.update_pos(&new_pos);
+            self.grid[self.robot.position] = '@';
+            return;
+        }
+
+        // clear out the spaces where olde boxes were
+        for current_positions in old_positions {
+            for current_position in current_positions {
+                self.grid[current_position.pos] = EMPTY_SPACE;
+            }
+        }
+
+        // Write all new positions
+        for positions in boxes_and_space {
+            for pos in positions {
+                self.grid[pos.pos] = pos.character;
+            }
+        }
+
+
+
+        // Update robot's position last
+        let new_pos = self.robot.position.next(*direction);
+        self.robot.update_pos(&new_pos);
+        self.grid[self.robot.position] = ROBOT_CHAR;
+    }
+
+    fn get_connected_boxes(&self, start_pos: &Pos, direction: &Direction) -> Option<Vec<Vec<PosChar>>> {
+        let mut moves = Vec::new();
+        let next_pos = start_pos.next(*direction);
+
+        // Left/right case is a lot simpler than up/down since we can't have 'pyramidal' cases
+        let can_move = match direction {
+            Direction::Left | Direction::Right => self.can_push_horizontal(&next_pos, direction, &mut moves),
+            Direction::Up | Direction::Down => self.can_push_vertical(&next_pos, direction, &mut moves),
+            _ => unreachable!("the robot only moves orthogonally")
+        };
+
+        if !can_move {
+            return None;
+        }
+
+        // If moves is empty, it means we're just moving into empty space, still a valid move though!
+        if moves.is_empty() {
+            return Some(vec![vec![PosChar {
+                pos: next_pos,
+                character: EMPTY_SPACE
+            }]]);
+        }
+
+        // Each box is a layer
+        let mut layers = Vec::new();
+        let mut current_layer = Vec::new();
+
+        for pos in moves {
+            current_layer.push(pos);
+            if current_layer.len() == 2 {
+                layers.push(current_layer);
+                current_layer = Vec::new();
+            }
+        }
+
+        // Handle any remaining single positions (should be empty spaces)
+        if !current_layer.is_empty() {
+            layers.push(current_layer);
+        }
+
+        Some(layers)
+    }
+
+    fn can_push_vertical(&self, pos: &Pos, direction: &Direction, moves: &mut Vec<PosChar>) -> bool {
+        let char = self.grid[*pos];
+
+        if char == EMPTY_SPACE {
+            return true;
+        }
+        if char == WALL {
+            return false;
+        }
+        if char == BOX_LEFT || char == BOX_RIGHT {
+            let dest = pos.next(*direction);
+
+            // Get the other box piece based on the current piece
+            let adjacent_direction = if char == BOX_RIGHT { Direction::Left } else { Direction::Right };
+            let adjacent_dest = dest.next(adjacent_direction);
+
+            // Check if both destination spaces are valid
+            let can = self.can_push_vertical(&dest, direction, moves) &&
+                self.can_push_vertical(&adjacent_dest, direction, moves);
+
+            if can {
+                // Add current box positions, not destinations
+                if !moves.iter().any(|m| m.pos == *pos) {
+                    moves.push(PosChar { pos: *pos, character: char });
+                    moves.push(PosChar {
+                        pos: pos.next(adjacent_direction),
+                        character: if char == BOX_LEFT { BOX_RIGHT } else { BOX_LEFT }
+                    });
+                }
+            }
+            return can;
+        }
+        false
+    }
+
+    fn can_push_horizontal(&self, pos: &Pos, direction: &Direction, moves: &mut Vec<PosChar>) -> bool {
+        let mut current_pos = *pos;
+
+        loop {
+            let char = self.grid[current_pos];
+
+            match char {
+                WALL => return false,
+                EMPTY_SPACE => return true,
+                BOX_RIGHT if direction == &Direction::Left => {
+                    let left_pos = current_pos.next(Direction::Left);
+                    moves.push(PosChar { pos: current_pos, character: BOX_RIGHT });
+                    moves.push(PosChar { pos: left_pos, character: BOX_LEFT });
+                    current_pos = left_pos.next(*direction);
+                },
+                BOX_LEFT if direction == &Direction::Right => {
+                    let right_pos = current_pos.next(Direction::Right);
+                    moves.push(PosChar { pos: current_pos, character: BOX_LEFT });
+                    moves.push(PosChar { pos: right_pos, character: BOX_RIGHT });
+                    current_pos = right_pos.next(*direction);
+                },
+                _ => return false
+            }
+        }
+    }
+}
+#[derive(Debug, Clone)]
+struct Robot {
+    position: Pos,
+    moves: Vec<Direction>
+}
+
+impl Robot {
+    fn update_pos(&mut self, new_pos: &Pos) {
+        self.position = *new_pos
+    }
+}
+
+// this is more for debugging than anything...makes code a bit ugly
+#[derive(Debug, Copy, Clone, PartialEq)]
+struct PosChar {
+    pos: Pos,
+    character: char
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SMALL_EXAMPLE: &str = "\
+########
+#..O.O.#
+##@.O..#
+#...O..#
+#.#.O..#
+#...O..#
+#......#
+########
+
+<^^>>>vv<v>>v<<
+";
+
+    const EXAMPLE: &str = "\
+##########
+#..O..O.O#
+#......O.#
+#.OO..O.O#
+#..O@..O.#
+#O#..O...#
+#O..O..O.#
+#.OO.O.OO#
+#....O...#
+##########
+
+<vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
+vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
+><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
+<<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
+^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
+^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
+>^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
+<><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
+^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
+v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
+";
+
+    #[test]
+    fn part_1_examples() {
+        assert_eq!(solve_part_1(&parse_input(SMALL_EXAMPLE)), 2028);
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 10092);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 9021);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Pos};
+use aoc_search::{all_shortest_paths, dijkstra};
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Parsed = Maze;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, maze: &Self::Parsed) -> String {
+        solve_part_1(maze).to_string()
+    }
+
+    fn part2(&self, maze: &Self::Parsed) -> String {
+        solve_part_2(maze).to_string()
+    }
+}
+
+fn solve_part_1(maze: &Maze) -> i32 {
+    let (_path, cost) = dijkstra(maze.start(), |&reindeer| maze.moves(reindeer), |&(pos, _)| pos == maze.end)
+        .expect("Valid maze must have a path to end");
+    cost
+}
+
+fn parse_input(input: &str) -> Maze {
+    let grid = Grid::parse(input);
+
+    let start = grid.find(&'S').expect("Expected a start 'S' in the maze");
+    let end = grid.find(&'E').expect("Expected an end 'E' in the maze");
+
+    Maze {
+        grid,
+        start,
+        end
+    }
+}
+
+fn solve_part_2(maze: &Maze) -> i32 {
+    // part 2 wants how many tiles are on any of the cheapest paths, not the cost again
+    let best_paths = all_shortest_paths(maze.start(), |&reindeer| maze.moves(reindeer), |&(pos, _)| pos == maze.end)
+        .expect("Valid maze must have a path to end");
+
+    // the same tile facing different ways is still one tile
+    let best_tiles: HashSet<Pos> = best_paths.nodes().into_iter()
+        .map(|(pos, _direction)| pos)
+        .collect();
+    best_tiles.len() as i32
+}
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+/// Where the reindeer is and which way it's facing
+type Reindeer = (Pos, Direction);
+
+impl Maze {
+    fn start(&self) -> Reindeer {
+        (self.start, Direction::Right)
+    }
+
+    fn is_wall(&self, pos: Pos) -> bool {
+        self.grid[pos] == '#'
+    }
+
+    fn can_move_to(&self, pos: Pos) -> bool {
+        !self.is_wall(pos)
+    }
+
+    /// Stepping forward costs 1, turning on the spot costs 1000. Only turns that face an open tile are worth making
+    fn moves(&self, (pos, direction): Reindeer) -> Vec<(Reindeer, i32)> {
+        let mut moves = Vec::new();
+
+        let next_pos = pos.next(direction);
+        if self.can_move_to(next_pos) {
+            moves.push(((next_pos, direction), 1));
+        }
+
+        for new_dir in [direction.rotate_clockwise(), direction.rotate_counterclockwise()] {
+            if self.can_move_to(pos.next(new_dir)) {
+                moves.push(((pos, new_dir), 1000));
+            }
+        }
+
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+    const SECOND_EXAMPLE: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+    #[test]
+    fn part_1_examples() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 7036);
+        assert_eq!(solve_part_1(&parse_input(SECOND_EXAMPLE)), 11048);
+    }
+
+    #[test]
+    fn part_2_examples() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 45);
+        assert_eq!(solve_part_2(&parse_input(SECOND_EXAMPLE)), 64);
+    }
+
+    #[test]
+    fn cheapest_path_cost_agrees() {
+        let maze = parse_input(SECOND_EXAMPLE);
+        let moves = |&reindeer: &Reindeer| maze.moves(reindeer);
+        let at_end = |&(pos, _): &Reindeer| pos == maze.end;
+
+        let (_path, cost) = dijkstra(maze.start(), moves, at_end).unwrap();
+        assert_eq!(all_shortest_paths(maze.start(), moves, at_end).unwrap().cost(), cost);
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_core::{sections, Solution};
+use aoc_vm::{find_quine, Machine, Registers, VmError};
+
+/// Real programs halt after a few hundred instructions, anything still going after this is stuck in a loop
+const STEP_BUDGET: usize = 1_000_000;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed = (Vec<usize>, Vec<usize>);
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, (register_values, program): &Self::Parsed) -> String {
+        solve_part_1(register_values, program)
+    }
+
+    fn part2(&self, (_, program): &Self::Parsed) -> String {
+        solve_part_2(program).to_string()
+    }
+}
+
+/// Input looks like
+///
+/// Register A: 729
+/// Register B: 0
+/// Register C: 0
+///
+/// Program: 0,1,5,4,3,0
+///
+/// and comes back as ([729, 0, 0], [0, 1, 5, 4, 3, 0])
+fn parse_input(input: &str) -> (Vec<usize>, Vec<usize>) {
+    let [registers, program] = sections(input)[..] else {
+        panic!("Expected registers and program separated by a blank line");
+    };
+
+    let register_values = registers.lines()
+        .map(|line| {
+            let (_, value) = line.split_once(':').expect("Register line is missing a ':'");
+            value.trim().parse::<usize>().unwrap()
+        })
+        .collect();
+
+    let (_, program) = program.split_once(':').expect("Program line is missing a ':'");
+    let program = program.trim()
+        .split(',')
+        .map(|num| num.trim().parse::<usize>().unwrap())
+        .collect();
+
+    (register_values, program)
+}
+
+fn solve_part_1(register_values: &[usize], program: &[usize]) -> String {
+    let output = run_program(register_values, program)
+        .unwrap_or_else(|err| panic!("Program crashed: {}", err));
+    output.iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join(",")
+}
+
+/// The puzzle input loaded into a machine, for stepping through it in the debugger
+pub fn machine(input: &str) -> Machine {
+    let (register_values, program) = parse_input(input);
+    Machine::new(program, initialize_registers(&register_values)).with_budget(STEP_BUDGET)
+}
+
+fn initialize_registers(register_values: &[usize]) -> Registers {
+    Registers::new(register_values[0], register_values[1], register_values[2])
+}
+
+fn run_program(register_values: &[usize], program: &[usize]) -> Result<Vec<usize>, VmError> {
+    Machine::new(program.to_vec(), initialize_registers(register_values))
+        .with_budget(STEP_BUDGET)
+        .run()
+}
+
+// Each pass of the program prints a digit from A's low bits and then shifts A down 3, so A gets built back up 3 bits
+// at a time starting from the last digit. find_quine backtracks when more than one choice of bits fits
+fn solve_part_2(program: &[usize]) -> usize {
+    find_quine(program)
+        .unwrap_or_else(|err| panic!("Couldn't find an A that prints the program: {}", err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let example = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+        let (register_values, program) = parse_input(example);
+        assert_eq!(solve_part_1(&register_values, &program), "4,6,3,5,6,3,5,2,1,0");
+    }
+
+    #[test]
+    fn part_2_example() {
+        let example = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+        let (_, program) = parse_input(example);
+        assert_eq!(solve_part_2(&program), 117440);
+    }
+
+    // the small examples from the instruction descriptions
+    #[test]
+    fn instruction_examples() {
+        assert_eq!(run_program(&[10, 0, 0], &[5, 0, 5, 1, 5, 4]), Ok(vec![0, 1, 2]));
+        assert_eq!(run_program(&[2024, 0, 0], &[0, 1, 5, 4, 3, 0]), Ok(vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]));
+
+        let mut machine = Machine::new(vec![1, 7], initialize_registers(&[0, 29, 0]));
+        machine.run().unwrap();
+        assert_eq!(machine.registers().b, 26);
+
+        let mut machine = Machine::new(vec![4, 0], initialize_registers(&[0, 2024, 43690]));
+        machine.run().unwrap();
+        assert_eq!(machine.registers().b, 44354);
+    }
+}
@@ -0,0 +1,159 @@
+use aoc_core::Solution;
+use aoc_grid::{Grid, Pos};
+use aoc_search::astar;
+
+// the example is a 7x7 grid with 12 bytes fallen
+const INPUT_DIMENSIONS: i32 = 71;
+const INPUT_BYTES_FALLEN: usize = 1024;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed = Maze;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        Maze::new(input, INPUT_DIMENSIONS, INPUT_DIMENSIONS)
+    }
+
+    fn part1(&self, maze: &Self::Parsed) -> String {
+        solve_part_1(maze, INPUT_BYTES_FALLEN).to_string()
+    }
+
+    fn part2(&self, maze: &Self::Parsed) -> String {
+        solve_part_2(maze)
+    }
+}
+
+fn solve_part_1(maze: &Maze, bytes_fallen: usize) -> i32 {
+    let mut maze = maze.clone();
+    for i in 0..bytes_fallen {
+        let corruption = maze.corruptions[i];
+        maze.corrupt_coord(&corruption);
+    }
+    if let Some(shortest) = shortest_path(&maze) {
+        return shortest
+    }
+    -1
+}
+
+/// Steps from the top left to the bottom right, walking around whatever's been corrupted so far
+fn shortest_path(maze: &Maze) -> Option<i32> {
+    let start = Pos::new(0, 0);
+    let end = Pos::new(maze.grid.height() as i32 - 1, maze.grid.width() as i32 - 1);
+
+    //maze.print_grid();
+    let steps = |pos: &Pos| {
+        pos.neighbours4()
+            .filter(|next_pos| maze.can_go(next_pos))
+            .map(|next_pos| (next_pos, 1))
+    };
+
+    astar(start, steps, |pos| pos.manhattan(&end), |pos| *pos == end)
+        .map(|(_path, cost)| cost)
+}
+
+fn solve_part_2(maze: &Maze) -> String {
+    let mut maze = maze.clone();
+    for i in 0..maze.corruptions.len() {
+        let corruption = maze.corruptions[i];
+        maze.corrupt_coord(&corruption);
+        //println!("CURR CORRUPTION {:?}", corruption);
+        if shortest_path(&maze).is_none() {
+            //println!("STOPS AT {},{} AT INDEX {}", corruption.1, corruption.0, i);
+            // the puzzle wants x,y which is col,row
+            return format!("{},{}", corruption.col, corruption.row);
+        }
+    }
+
+    String::from("never blocked")
+}
+
+#[derive(Clone)]
+pub struct Maze {
+    grid: Grid<char>,
+    corruptions: Vec<Pos>,
+}
+
+impl Maze {
+    const WALL: char = '#';
+    const SPACE: char = '.';
+
+    fn new(input: &str, width: i32, length: i32) -> Self {
+        let grid = Grid::new(width as usize, length as usize, Self::SPACE);
+
+        let mut corruptions = Vec::new();
+
+        let comma = ',';
+
+        for line in input.lines() {
+            let mut coords = line.split(comma);
+            let x = coords.next().unwrap().parse::<i32>().unwrap();
+            let y = coords.next().unwrap().parse::<i32>().unwrap();
+            corruptions.push(Pos::new(y, x));
+        }
+
+        Maze {
+            grid,
+            corruptions,
+        }
+    }
+
+    fn corrupt_coord(&mut self, coord: &Pos) {
+        self.grid[*coord] = Self::WALL
+    }
+
+    fn is_in_bounds(&self, pos: &Pos) -> bool {
+        self.grid.in_bounds(*pos)
+    }
+
+    fn is_wall(&self, pos: &Pos) -> bool {
+        self.grid[*pos] == Self::WALL
+    }
+
+    fn can_go(&self, pos: &Pos) -> bool {
+        self.is_in_bounds(pos) && !self.is_wall(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&Maze::new(EXAMPLE, 7, 7), 12), 22);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&Maze::new(EXAMPLE, 7, 7)), "6,1");
+    }
+}
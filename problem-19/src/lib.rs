@@ -1,25 +1,24 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use aoc_core::{sections, Solution};
 
-mod gimme_input;
+pub struct Day19;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-
-    //println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT_STRIPES, gimme_input::INPUT_DESIGNS));
-    //println!("PART 1 {:?}", solve_part_1(gimme_input::TEST_INPUT_STRIPES, gimme_input::TEST_INPUT_DESIGNS));
-
-    //println!("PART 2 {}", solve_part_2(gimme_input::TEST_INPUT_STRIPES, gimme_input::TEST_INPUT_DESIGNS));
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT_STRIPES, gimme_input::INPUT_DESIGNS));
+impl Solution for Day19 {
+    type Parsed = Linens;
 
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
 
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fn part1(&self, linens: &Self::Parsed) -> String {
+        solve_part_1(linens).to_string()
+    }
 
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+    fn part2(&self, linens: &Self::Parsed) -> String {
+        solve_part_2(linens).to_string()
+    }
 }
 
-fn solve_part_1(stripes: &str, designs: &str) -> usize {
-    let linens = parse_input(stripes, designs);
-
+fn solve_part_1(linens: &Linens) -> usize {
     linens.designs.iter()
         .filter(|design| can_make_design_recursive(&linens.stripes, design))
         .count()
@@ -35,10 +34,9 @@ fn can_make_design_recursive(stripes: &Vec<String>, design: &str) -> bool {
         for stripe in stripes {
             let updated_towel = current.to_owned() + stripe;
             // We can do an early exit here if our updated prefix doesn't match what we've built
-            if design.starts_with(&updated_towel) {
-                if build_string(stripes, design, &updated_towel) {
-                    return true;
-                }
+            if design.starts_with(&updated_towel)
+                && build_string(stripes, design, &updated_towel) {
+                return true;
             }
         }
 
@@ -76,7 +74,12 @@ fn get_towel_design_counts(stripes: &Vec<String>, design: &str) -> usize {
     prefix_counters[design_len]
 }
 
-fn parse_input(stripes: &str, designs: &str) -> Linens {
+// the stripe patterns are one comma separated line, then a blank line, then one design per line
+fn parse_input(input: &str) -> Linens {
+    let [stripes, designs] = sections(input)[..] else {
+        panic!("Expected towel patterns and designs separated by a blank line");
+    };
+
     let parsed_stripes = stripes.split(", ")
         .map(|s| s.trim().to_string())
         .collect();
@@ -91,17 +94,56 @@ fn parse_input(stripes: &str, designs: &str) -> Linens {
     }
 }
 
-fn solve_part_2(stripes: &str, designs: &str) -> usize {
-    let linens = parse_input(stripes, designs);
-
+fn solve_part_2(linens: &Linens) -> usize {
     linens.designs.iter()
         .map(|design| get_towel_design_counts(&linens.stripes, design))
         .sum()
 }
 
-struct Linens {
+pub struct Linens {
     // e.g., r, wr, b
     stripes: Vec<String>,
     // e.g., brwrr
     designs: Vec<String>
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 6);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 16);
+    }
+
+    #[test]
+    fn design_counts() {
+        let stripes: Vec<String> = ["r", "wr", "b", "g", "bwu", "rb", "gb", "br"].iter()
+            .map(|stripe| stripe.to_string())
+            .collect();
+
+        assert_eq!(get_towel_design_counts(&stripes, "brwrr"), 2);
+        assert_eq!(get_towel_design_counts(&stripes, "gbbr"), 4);
+        assert_eq!(get_towel_design_counts(&stripes, "rrbgbr"), 6);
+        assert_eq!(get_towel_design_counts(&stripes, "ubwu"), 0);
+        assert_eq!(get_towel_design_counts(&stripes, ""), 1);
+    }
+}
@@ -1,15 +1,27 @@
 // PROBLEM STATEMENT: https://adventofcode.com/2024/day/2
 
-mod gimme_input;
+use aoc_core::Solution;
 
 const MIN_ACCEPTABLE_DIFFERENCE: i32 = 1;
 const MAX_ACCEPTABLE_DIFFERENCE: i32 = 3;
 
-fn main() {
-    let safe_reports = get_safe_reports(gimme_input::INPUT);
-    println!("PART 1 {}", get_safe_reports(gimme_input::INPUT));
+pub struct Day2;
 
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT) + safe_reports);
+impl Solution for Day2 {
+    type Parsed = Vec<Vec<i32>>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_reports(input)
+    }
+
+    fn part1(&self, reports: &Self::Parsed) -> String {
+        get_safe_reports(reports).to_string()
+    }
+
+    // part 2 only counts the dampened reports, the already safe ones get added back in here
+    fn part2(&self, reports: &Self::Parsed) -> String {
+        (solve_part_2(reports) + get_safe_reports(reports)).to_string()
+    }
 }
 
 fn parse_reports(input: &str) -> Vec<Vec<i32>> {
@@ -22,10 +34,7 @@ fn parse_reports(input: &str) -> Vec<Vec<i32>> {
         })
         .collect()
 }
-fn get_safe_reports(input: &str) -> i32 {
-    // clean input to prevent aneurysms
-    let parsed_reports: Vec<Vec<i32>> = parse_reports(input);
-
+fn get_safe_reports(parsed_reports: &[Vec<i32>]) -> i32 {
     // now just sum up all the safe reports
     parsed_reports.iter()
         .map(|report| is_report_safe(report))
@@ -33,20 +42,20 @@ fn get_safe_reports(input: &str) -> i32 {
 }
 
 // returning an integer lets us do a quick sum above...and leads to ugliness in part 2...yolo stick with it
-fn is_report_safe(report: &Vec<i32>) -> i32 {
+fn is_report_safe(report: &[i32]) -> i32 {
     let is_consistent = is_trend_consistent(report);
     let is_safe = is_acceptable_threshold(report);
 
-    if report.len() > 0 && is_consistent && is_safe {
+    if !report.is_empty() && is_consistent && is_safe {
         1
     } else {
        0
     }
 }
 
-fn is_trend_consistent(report: &Vec<i32>) -> bool {
+fn is_trend_consistent(report: &[i32]) -> bool {
     // yea could def be streamlined but whatever
-    let is_increasing = report.get(0) < report.get(1);
+    let is_increasing = report.first() < report.get(1);
 
     if is_increasing {
         // sliding windows in rust wow. Time to become reliant on this and become awful at other languages
@@ -58,17 +67,15 @@ fn is_trend_consistent(report: &Vec<i32>) -> bool {
     }
 }
 
-fn is_acceptable_threshold(report: &Vec<i32>) -> bool {
+fn is_acceptable_threshold(report: &[i32]) -> bool {
     report.windows(2)
         .map(|window| (window[0] - window[1]).abs())
-        .all(|abs_diff| MIN_ACCEPTABLE_DIFFERENCE <= abs_diff && abs_diff <= MAX_ACCEPTABLE_DIFFERENCE)
+        .all(|abs_diff| (MIN_ACCEPTABLE_DIFFERENCE..=MAX_ACCEPTABLE_DIFFERENCE).contains(&abs_diff))
 }
 
-fn solve_part_2(input: &str) -> i32 {
-    let reports: Vec<Vec<i32>> = parse_reports(input);
-
+fn solve_part_2(reports: &[Vec<i32>]) -> i32 {
     // let's get the unsafe reports
-    let unsafe_reports: Vec<Vec<i32>> = reports.into_iter()
+    let unsafe_reports: Vec<&Vec<i32>> = reports.iter()
         .filter(|report| is_report_safe(report) == 0)
         .collect();
 
@@ -91,4 +98,37 @@ fn solve_part_2(input: &str) -> i32 {
         }
     }
     sum
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(get_safe_reports(&parse_reports(EXAMPLE)), 2);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(Day2.part2(&Day2.parse(EXAMPLE)), "4");
+    }
+
+    #[test]
+    fn trend_consistency() {
+        assert!(is_trend_consistent(&[7, 6, 4, 2, 1]));
+        assert!(is_trend_consistent(&[1, 2, 7, 8, 9]));
+        assert!(!is_trend_consistent(&[1, 3, 2, 4, 5]));
+        // a repeated level is neither increasing nor decreasing
+        assert!(!is_trend_consistent(&[8, 6, 4, 4, 1]));
+    }
+}
@@ -0,0 +1,128 @@
+use aoc_core::Solution;
+use aoc_grid::{Grid, Pos};
+use aoc_search::bfs;
+
+// the example track is too short for anything to save 100, its walkthrough lists every saving instead
+const MIN_SAVINGS: i32 = 100;
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Parsed = Maze;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        Maze::new(input)
+    }
+
+    fn part1(&self, maze: &Self::Parsed) -> String {
+        solve_part_1(maze, MIN_SAVINGS).to_string()
+    }
+
+    fn part2(&self, maze: &Self::Parsed) -> String {
+        solve_part_2(maze, MIN_SAVINGS).to_string()
+    }
+}
+
+fn solve_part_1(maze: &Maze, min_savings: i32) -> i32 {
+    count_cheats(maze, 2, min_savings)
+}
+
+// it's basically identical to part 1, cheats just get to last a lot longer
+fn solve_part_2(maze: &Maze, min_savings: i32) -> i32 {
+    count_cheats(maze, 20, min_savings)
+}
+
+/// There's only the one track, so a cheat is just jumping from one spot on it to a later one through the walls.
+/// A cheat is only told apart by where it starts and ends, and it saves however far along the track the jump takes
+/// us minus the picoseconds spent jumping
+fn count_cheats(maze: &Maze, cheat_limit: i32, min_savings: i32) -> i32 {
+    let steps = |pos: &Pos| pos.neighbours4().filter(|next_pos| maze.can_go(next_pos));
+    let Some(path) = bfs(maze.start, steps, |pos| *pos == maze.end) else {
+        return 0;
+    };
+
+    // how far along the track each spot is is just its index in the path
+    let mut count = 0;
+    for (from_cost, from) in path.iter().enumerate() {
+        for (to_cost, to) in path.iter().enumerate().skip(from_cost + 1) {
+            let cheat_length = from.manhattan(to);
+            if cheat_length <= cheat_limit && (to_cost - from_cost) as i32 - cheat_length >= min_savings {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub struct Maze {
+    grid: Grid<char>,
+    start: Pos,
+    end: Pos,
+}
+
+impl Maze {
+    const WALL: char = '#';
+
+    fn new(input: &str) -> Self {
+        let grid = Grid::parse(input);
+
+        let start = grid.find(&'S').expect("Expected a start 'S' on the track");
+        let end = grid.find(&'E').expect("Expected an end 'E' on the track");
+
+        Maze {
+            grid,
+            start,
+            end,
+        }
+    }
+
+    fn is_in_bounds(&self, pos: &Pos) -> bool {
+        self.grid.in_bounds(*pos)
+    }
+
+    fn is_wall(&self, pos: &Pos) -> bool {
+        self.grid[*pos] == Self::WALL
+    }
+
+    fn can_go(&self, pos: &Pos) -> bool {
+        self.is_in_bounds(pos) && !self.is_wall(pos)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&Maze::new(EXAMPLE), 64), 1);
+        assert_eq!(solve_part_1(&Maze::new(EXAMPLE), 20), 5);
+        assert_eq!(solve_part_1(&Maze::new(EXAMPLE), 2), 44);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&Maze::new(EXAMPLE), 76), 3);
+        assert_eq!(solve_part_2(&Maze::new(EXAMPLE), 74), 7);
+        assert_eq!(solve_part_2(&Maze::new(EXAMPLE), 50), 285);
+    }
+}
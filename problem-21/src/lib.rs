@@ -1,21 +1,39 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 
-fn main() {
-    let codes = ["029A", "980A", "179A", "456A", "379A"];
-    // let codes = ["208A", "586A", "341A", "463A", "593A"];
+use aoc_core::Solution;
+use aoc_search::all_shortest_paths;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Parsed = Vec<String>;
+
+    // 25 robot keypads deep, enumerating every sequence like part 1 does never finishes
+    const HAS_PART_2: bool = false;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, codes: &Self::Parsed) -> String {
+        solve_part_1(codes).to_string()
+    }
+}
+
+fn parse_input(input: &str) -> Vec<String> {
+    input.lines().map(String::from).collect()
+}
+
+fn solve_part_1(codes: &[String]) -> u32 {
     let mut total_complexity = 0;
 
     for code in codes {
-        if let Some((path, complexity)) = solve_code(code) {
-            println!("Code: {}", code);
-            println!("Path length: {}", path.len());
-            println!("Complexity: {}", complexity);
-            println!("Path: {:?}\n", path);
+        if let Some((_, complexity)) = solve_code(code) {
             total_complexity += complexity;
         }
     }
 
-    println!("Total complexity: {}", total_complexity);
+    total_complexity
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -24,6 +42,45 @@ struct Position {
     y: i32,
 }
 
+impl Position {
+    fn moves(&self) -> [(Position, char); 4] {
+        [
+            (Position { x: self.x + 1, y: self.y }, '>'),
+            (Position { x: self.x - 1, y: self.y }, '<'),
+            (Position { x: self.x, y: self.y + 1}, '^'),
+            (Position { x: self.x, y: self.y - 1}, 'v'),
+        ]
+    }
+}
+
+/// Every button sequence that's tied for the fewest presses from one key to another without crossing a gap
+fn shortest_moves(from: Position, to: Position, is_valid_position: impl Fn(Position) -> bool) -> Vec<Vec<char>> {
+    let steps = |pos: &Position| {
+        pos.moves().into_iter()
+            .filter(|&(new_pos, _)| is_valid_position(new_pos))
+            .map(|(new_pos, _)| (new_pos, 1))
+            .collect::<Vec<_>>()
+    };
+
+    let Some(shortest_paths) = all_shortest_paths(from, steps, |&pos| pos == to) else {
+        return Vec::new();
+    };
+
+    // the search hands back the keys we walk over, the robot needs the arrows that get it from one to the next
+    shortest_paths.paths().iter()
+        .map(|path| {
+            path.windows(2)
+                .map(|step| {
+                    step[0].moves().into_iter()
+                        .find(|&(new_pos, _)| new_pos == step[1])
+                        .map(|(_, direction)| direction)
+                        .unwrap()
+                })
+                .collect()
+        })
+        .collect()
+}
+
 #[derive(Debug)]
 struct NumericKeypad {
     positions: HashMap<char, Position>,
@@ -58,55 +115,7 @@ impl NumericKeypad {
     }
 
     fn moves_between(&self, from: Position, to: Position) -> Vec<Vec<char>> {
-        let mut queue = VecDeque::new();
-        let mut visited = HashMap::new();
-        queue.push_back((from, Vec::new()));
-        visited.insert(from, Vec::new());
-
-        let mut shortest_paths = Vec::new();
-        let mut shortest_len = None;
-
-        while let Some((pos, path)) = queue.pop_front() {
-            if pos == to {
-                match shortest_len {
-                    None => {
-                        shortest_len = Some(path.len());
-                        shortest_paths.push(path.clone());
-                    }
-                    Some(len) if path.len() == len => {
-                        shortest_paths.push(path.clone());
-                    }
-                    Some(len) if path.len() > len => break,
-                    _ => {}
-                }
-                continue;
-            }
-
-            // Try all possible moves
-            let moves = [
-                (Position { x: pos.x + 1, y: pos.y }, '>'),
-                (Position { x: pos.x - 1, y: pos.y }, '<'),
-                (Position { x: pos.x, y: pos.y + 1}, '^'),
-                (Position { x: pos.x, y: pos.y - 1}, 'v'),
-            ];
-
-            for (new_pos, direction) in moves {
-                if !self.is_valid_position(new_pos) {
-                    continue;
-                }
-
-                let mut new_path = path.clone();
-                new_path.push(direction);
-
-                if !visited.contains_key(&new_pos) ||
-                    visited[&new_pos].len() >= new_path.len() {
-                    visited.insert(new_pos, new_path.clone());
-                    queue.push_back((new_pos, new_path));
-                }
-            }
-        }
-
-        shortest_paths
+        shortest_moves(from, to, |pos| self.is_valid_position(pos))
     }
 
     fn sequence_for_code(&self, code: &str) -> Vec<Vec<char>> {
@@ -168,53 +177,7 @@ impl DirectionalKeypad {
             return cached.clone();
         }
 
-        let mut queue = VecDeque::new();
-        let mut visited = HashMap::new();
-        queue.push_back((from, Vec::new()));
-        visited.insert(from, Vec::new());
-
-        let mut shortest_paths = Vec::new();
-        let mut shortest_len = None;
-
-        while let Some((pos, path)) = queue.pop_front() {
-            if pos == to {
-                match shortest_len {
-                    None => {
-                        shortest_len = Some(path.len());
-                        shortest_paths.push(path.clone());
-                    }
-                    Some(len) if path.len() == len => {
-                        shortest_paths.push(path.clone());
-                    }
-                    Some(len) if path.len() > len => break,
-                    _ => {}
-                }
-                continue;
-            }
-
-            let moves = [
-                (Position { x: pos.x + 1, y: pos.y }, '>'),
-                (Position { x: pos.x - 1, y: pos.y }, '<'),
-                (Position { x: pos.x, y: pos.y + 1}, '^'),
-                (Position { x: pos.x, y: pos.y - 1}, 'v'),
-            ];
-
-            for (new_pos, direction) in moves {
-                if !self.is_valid_position(new_pos) {
-                    continue;
-                }
-
-                let mut new_path = path.clone();
-                new_path.push(direction);
-
-                if !visited.contains_key(&new_pos) ||
-                    visited[&new_pos].len() >= new_path.len() {
-                    visited.insert(new_pos, new_path.clone());
-                    queue.push_back((new_pos, new_path));
-                }
-            }
-        }
-
+        let shortest_paths = shortest_moves(from, to, |pos| self.is_valid_position(pos));
         self.cached_moves.insert((from, to), shortest_paths.clone());
         shortest_paths
     }
@@ -266,8 +229,7 @@ fn solve_code(code: &str) -> Option<(Vec<char>, u32)> {
 
     let mut directional_pad = DirectionalKeypad::new();
 
-    for _pad_num in 0..2 {
-        println!("CODE :: {} ITER :: {}", code, _pad_num);
+    for _ in 0..2 {
         let mut next_sequences = Vec::new();
         let mut shortest_len = usize::MAX;
 
@@ -301,4 +263,29 @@ fn solve_code(code: &str) -> Option<(Vec<char>, u32)> {
         let len = seq.len();
         (seq, len as u32 * numeric_part)
     })
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 126384);
+    }
+
+    #[test]
+    fn single_code_complexity() {
+        let (path, complexity) = solve_code("029A").unwrap();
+        assert_eq!(path.len(), 68);
+        assert_eq!(complexity, 68 * 29);
+    }
+}
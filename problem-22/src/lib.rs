@@ -1,33 +1,36 @@
 use std::collections::HashMap;
-use std::time::{SystemTime, UNIX_EPOCH};
 
-mod gimme_input;
+use aoc_core::Solution;
 
 const SECRET_ITERS: usize = 2000;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+pub struct Day22;
 
-    //println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT));
-    //println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT));
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT));
+impl Solution for Day22 {
+    type Parsed = Vec<i64>;
 
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
 
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
-}
+    fn part1(&self, numbers: &Self::Parsed) -> String {
+        solve_part_1(numbers).to_string()
+    }
 
-fn solve_part_1(input: &str) -> i64 {
-    let parsed_nums = parse_input(input);
+    fn part2(&self, numbers: &Self::Parsed) -> String {
+        solve_part_2(numbers).to_string()
+    }
+}
 
+fn solve_part_1(parsed_nums: &[i64]) -> i64 {
     parsed_nums.iter()
-        .map(|num| calculate_secret(num))
+        .map(calculate_secret)
         .sum()
 }
 
 fn calculate_secret(num: &i64) -> i64 {
     let mut secret = *num;
-    for i in 0..2000 {
+    for _i in 0..2000 {
         let mult_mix_64 = mult_mix_64(&secret);
         let div_mix = div_mix(&mult_mix_64);
         let mult_mix_2048 = mult_mix_2048(&div_mix);
@@ -65,16 +68,15 @@ fn prune(num: &i64) -> i64 {
 }
 
 fn gen_secret(num: &i64) -> i64 {
-    let mult_mix_64 = mult_mix_64(&num);
+    let mult_mix_64 = mult_mix_64(num);
     let div_mix = div_mix(&mult_mix_64);
     mult_mix_2048(&div_mix)
 }
 
-fn solve_part_2(input: &str) -> i64 {
-    let test_nums = parse_input(input);
+fn solve_part_2(test_nums: &[i64]) -> i64 {
     let mut all_patterns = Vec::new();
 
-    for &start in &test_nums {
+    for &start in test_nums {
         let mut prices = Vec::new();
         let mut secret = start;
 
@@ -110,9 +112,32 @@ fn solve_part_2(input: &str) -> i64 {
 
     final_patterns.into_iter()
         .max_by_key(|&(_, bananas)| bananas)
-        .map(|(pattern, bananas)| {
-            println!("Best pattern found: {:?} giving {} bananas", pattern, bananas);
-            bananas
-        })
+        .map(|(_, bananas)| bananas)
         .unwrap_or(0)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input("1\n10\n100\n2024\n")), 37327623);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input("1\n2\n3\n2024\n")), 23);
+    }
+
+    #[test]
+    fn next_secrets() {
+        let expected = [15887950, 16495136, 527345, 704524, 1553684, 12683156, 11100544, 12249484, 7753432, 5908254];
+
+        let mut secret = 123;
+        for next in expected {
+            secret = gen_secret(&secret);
+            assert_eq!(secret, next);
+        }
+    }
+}
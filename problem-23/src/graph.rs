@@ -0,0 +1,155 @@
+//! Counting cliques of a fixed size over the adjacency map parse_input_graph builds.
+//!
+//! Every edge gets pointed from the node with fewer connections to the one with more (names break ties), and a clique
+//! is only ever grown along those arrows. That way each clique gets found once, from its lowest ranked node, and
+//! nobody has many neighbours ahead of them to look through
+
+use std::collections::{HashMap, HashSet};
+
+/// Triangles with at least one node `keep` likes
+pub(crate) fn count_triangles(graph: &HashMap<String, HashSet<String>>, keep: impl Fn(&str) -> bool) -> usize {
+    count_cliques(graph, 3, keep)
+}
+
+/// Groups of `size` nodes all connected to each other, with at least one node `keep` likes
+pub(crate) fn count_cliques(graph: &HashMap<String, HashSet<String>>, size: usize, keep: impl Fn(&str) -> bool) -> usize {
+    let mut count = 0;
+    for_each_clique(graph, size, |clique| {
+        if clique.iter().any(|node| keep(node)) {
+            count += 1;
+        }
+    });
+
+    count
+}
+
+/// Hands every clique of exactly `size` nodes to `visit`, once each, in rank order inside. The clique is only
+/// borrowed, nothing gets collected unless the caller wants it
+fn for_each_clique<'a>(graph: &'a HashMap<String, HashSet<String>>, size: usize, mut visit: impl FnMut(&[&'a str])) {
+    if size == 0 {
+        return;
+    }
+
+    let mut ranked = graph.keys().map(String::as_str).collect::<Vec<_>>();
+    ranked.sort_by_key(|node| (graph[*node].len(), *node));
+    let rank = ranked.iter()
+        .enumerate()
+        .map(|(rank, node)| (*node, rank))
+        .collect::<HashMap<_, _>>();
+
+    for node in ranked {
+        let mut ahead = graph[node].iter()
+            .map(String::as_str)
+            .filter(|neighbour| rank[neighbour] > rank[node])
+            .collect::<Vec<_>>();
+        ahead.sort_by_key(|neighbour| rank[neighbour]);
+
+        grow(graph, &mut vec![node], &ahead, size, &mut visit);
+    }
+}
+
+/// `candidates` are connected to everything in `clique` and ranked after all of it, in rank order
+fn grow<'a>(graph: &'a HashMap<String, HashSet<String>>,
+            clique: &mut Vec<&'a str>,
+            candidates: &[&'a str],
+            size: usize,
+            visit: &mut impl FnMut(&[&'a str])) {
+    if clique.len() == size {
+        visit(clique);
+        return;
+    }
+
+    for (index, &candidate) in candidates.iter().enumerate() {
+        // not enough left to fill the clique
+        if candidates.len() - index < size - clique.len() {
+            break;
+        }
+
+        let neighbours = &graph[candidate];
+        let remaining = candidates[index + 1..].iter()
+            .filter(|other| neighbours.contains(**other))
+            .copied()
+            .collect::<Vec<_>>();
+
+        clique.push(candidate);
+        grow(graph, clique, &remaining, size, visit);
+        clique.pop();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_input_graph;
+    use aoc_core::Lcg;
+
+    fn cliques(graph: &HashMap<String, HashSet<String>>, size: usize) -> Vec<Vec<&str>> {
+        let mut found = Vec::new();
+        for_each_clique(graph, size, |clique| found.push(clique.to_vec()));
+        found
+    }
+
+    fn sorted(mut cliques: Vec<Vec<&str>>) -> Vec<String> {
+        let mut joined = cliques.iter_mut()
+            .map(|clique| {
+                clique.sort();
+                clique.join(",")
+            })
+            .collect::<Vec<_>>();
+        joined.sort();
+        joined
+    }
+
+    #[test]
+    fn example_triangles() {
+        let graph = parse_input_graph(crate::tests::EXAMPLE);
+
+        assert_eq!(sorted(cliques(&graph, 3)), [
+            "aq,cg,yn", "aq,vc,wq", "co,de,ka", "co,de,ta", "co,ka,ta", "de,ka,ta",
+            "kh,qp,ub", "qp,td,wh", "tb,vc,wq", "tc,td,wh", "td,wh,yn", "ub,vc,wq",
+        ]);
+        assert_eq!(count_triangles(&graph, |name| name.starts_with('t')), 7);
+        assert_eq!(count_triangles(&graph, |name| name == "wh"), 3);
+    }
+
+    #[test]
+    fn other_sizes() {
+        let graph = parse_input_graph(crate::tests::EXAMPLE);
+
+        assert_eq!(sorted(cliques(&graph, 4)), ["co,de,ka,ta"]);
+        assert!(cliques(&graph, 5).is_empty());
+        assert_eq!(cliques(&graph, 2).len(), crate::tests::EXAMPLE.lines().count());
+        assert_eq!(count_cliques(&graph, 1, |name| name.starts_with('t')), 4);
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        let mut rng = Lcg::new(20);
+
+        for _ in 0..20 {
+            let names = (0..9).map(|n| format!("n{}", n)).collect::<Vec<_>>();
+            let mut lines = Vec::new();
+            for a in 0..names.len() {
+                for b in a + 1..names.len() {
+                    if rng.below(3) != 0 {
+                        lines.push(format!("{}-{}", names[a], names[b]));
+                    }
+                }
+            }
+            let graph = parse_input_graph(&lines.join("\n"));
+            let nodes = graph.keys().collect::<Vec<_>>();
+
+            for size in 3..=5 {
+                let brute_force = (0..1u32 << nodes.len())
+                    .filter(|mask| mask.count_ones() as usize == size)
+                    .filter(|mask| {
+                        let subset = (0..nodes.len()).filter(|i| mask & (1 << i) != 0).collect::<Vec<_>>();
+                        subset.iter().all(|&a| subset.iter().all(|&b| a == b || graph[nodes[a]].contains(nodes[b])))
+                    })
+                    .count();
+
+                assert_eq!(cliques(&graph, size).len(), brute_force, "size {}", size);
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::Solution;
+
+mod graph;
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Parsed = HashMap<String, HashSet<String>>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input_graph(input)
+    }
+
+    fn part1(&self, graph: &Self::Parsed) -> String {
+        solve_part_1(graph).to_string()
+    }
+
+    fn part2(&self, graph: &Self::Parsed) -> String {
+        solve_part_2(graph).to_string()
+    }
+}
+
+/// Sets of three computers all connected to each other where at least one might be the Chief Historian's
+fn solve_part_1(graph: &HashMap<String, HashSet<String>>) -> usize {
+    graph::count_triangles(graph, |name| name.starts_with('t'))
+}
+
+fn parse_input_graph(input: &str) -> HashMap<String, HashSet<String>> {
+    let mut graph: HashMap<String, HashSet<String>> = HashMap::new();
+
+    for line in input.lines() {
+        let mut parts = line.split('-');
+        let left_pair = parts.next().unwrap().to_string();
+        let right_pair = parts.next().unwrap().to_string();
+
+        graph.entry(left_pair.clone()).or_default().insert(right_pair.clone());
+
+        graph.entry(right_pair).or_default().insert(left_pair);
+    }
+
+    graph
+}
+
+/// Every clique that can't grow any more, each sorted and the list sorted too
+fn maximal_cliques(graph: &HashMap<String, HashSet<String>>) -> Vec<Vec<String>> {
+    let mut cliques = Vec::new();
+    bron_kerbosch(graph, &mut Vec::new(), graph.keys().map(String::as_str).collect(), HashSet::new(), &mut |clique| {
+        let mut clique = clique.iter().map(|node| node.to_string()).collect::<Vec<_>>();
+        clique.sort();
+        cliques.push(clique);
+    });
+
+    cliques.sort();
+    cliques
+}
+
+/// The biggest of the maximal cliques. Ties go to whichever sorts first so the answer doesn't depend on hash order
+fn find_largest_clique(graph: &HashMap<String, HashSet<String>>) -> Vec<String> {
+    maximal_cliques(graph)
+        .into_iter()
+        .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+        .unwrap_or_default()
+}
+
+/// Bron–Kerbosch with pivoting. `clique` is what's been built so far, `candidates` are nodes connected to all of it
+/// that haven't been tried yet, and `excluded` are nodes connected to all of it whose cliques were already reported.
+/// Any maximal clique has to include the pivot or one of its non-neighbours, so only those get tried, and picking the
+/// pivot with the most candidate neighbours skips the most
+fn bron_kerbosch<'a>(graph: &'a HashMap<String, HashSet<String>>,
+                     clique: &mut Vec<&'a str>,
+                     mut candidates: HashSet<&'a str>,
+                     mut excluded: HashSet<&'a str>,
+                     report: &mut impl FnMut(&[&'a str])) {
+    let Some(pivot) = candidates.union(&excluded)
+        .max_by_key(|node| candidates.iter().filter(|candidate| graph[**node].contains(**candidate)).count())
+        .copied() else {
+        // nothing left to add and nothing left out, so it can't grow
+        report(clique);
+        return;
+    };
+
+    let to_try = candidates.iter()
+        .filter(|node| !graph[pivot].contains(**node))
+        .copied()
+        .collect::<Vec<_>>();
+
+    for node in to_try {
+        let neighbours = &graph[node];
+        clique.push(node);
+        bron_kerbosch(graph,
+                      clique,
+                      candidates.iter().filter(|other| neighbours.contains(**other)).copied().collect(),
+                      excluded.iter().filter(|other| neighbours.contains(**other)).copied().collect(),
+                      report);
+        clique.pop();
+
+        candidates.remove(node);
+        excluded.insert(node);
+    }
+}
+
+/// The LAN party is the biggest set of computers all connected to each other, the password is their names sorted
+fn solve_part_2(graph: &HashMap<String, HashSet<String>>) -> String {
+    find_largest_clique(graph).join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::Lcg;
+
+    pub(crate) const EXAMPLE: &str = "\
+kh-tc
+qp-kh
+de-cg
+ka-co
+yn-aq
+qp-ub
+cg-tb
+vc-aq
+tb-ka
+wh-tc
+yn-cg
+kh-ub
+ta-co
+de-co
+tc-td
+tb-wq
+wh-td
+ta-ka
+td-qp
+aq-cg
+wq-ub
+ub-vc
+de-ta
+wq-aq
+wq-vc
+wh-yn
+ka-de
+kh-ta
+co-tc
+wh-qp
+tb-vc
+td-yn
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input_graph(EXAMPLE)), 7);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input_graph(EXAMPLE)), "co,de,ka,ta");
+    }
+
+    fn is_clique(graph: &HashMap<String, HashSet<String>>, nodes: &[String]) -> bool {
+        nodes.iter().all(|node| nodes.iter().all(|other| other == node || graph[node].contains(other)))
+    }
+
+    #[test]
+    fn example_maximal_cliques() {
+        let graph = parse_input_graph(EXAMPLE);
+        let cliques = maximal_cliques(&graph);
+
+        for clique in &cliques {
+            assert!(is_clique(&graph, clique), "{:?} isn't a clique", clique);
+            let can_grow = graph.keys().any(|node| !clique.contains(node) && clique.iter().all(|member| graph[node].contains(member)));
+            assert!(!can_grow, "{:?} isn't maximal", clique);
+        }
+        assert_eq!(cliques.iter().filter(|clique| clique.len() == 4).collect::<Vec<_>>(), vec![&["co", "de", "ka", "ta"]]);
+    }
+
+    #[test]
+    fn agrees_with_brute_force() {
+        // seeded so the graphs are the same every run
+        let mut rng = Lcg::new(23);
+
+        for _ in 0..50 {
+            let names = (0..10).map(|n| format!("n{}", n)).collect::<Vec<_>>();
+            let mut lines = Vec::new();
+            for a in 0..names.len() {
+                for b in a + 1..names.len() {
+                    if rng.below(2) == 0 {
+                        lines.push(format!("{}-{}", names[a], names[b]));
+                    }
+                }
+            }
+            let graph = parse_input_graph(&lines.join("\n"));
+            let nodes = graph.keys().cloned().collect::<Vec<_>>();
+
+            let brute_force = (0..1u32 << nodes.len())
+                .map(|mask| (0..nodes.len()).filter(|i| mask & (1 << i) != 0).map(|i| nodes[i].clone()).collect::<Vec<_>>())
+                .filter(|subset| is_clique(&graph, subset))
+                .map(|subset| subset.len())
+                .max()
+                .unwrap();
+
+            assert_eq!(find_largest_clique(&graph).len(), brute_force);
+        }
+    }
+}
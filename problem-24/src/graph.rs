@@ -0,0 +1,326 @@
+//! Drawing the circuit, for when find_adder_faults flags something and you want to see why. Gates and wires are
+//! separate nodes, each bit's adder gets its own box, and the suspicious wires are red
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::{input_wire_bit, GateOp, Operation, WireGates};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphFormat {
+    /// Graphviz, `dot -Tsvg circuit.dot -o circuit.svg`
+    Dot,
+    /// Renders on GitHub inside a ```mermaid block
+    Mermaid,
+}
+
+impl GraphFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dot" => Some(GraphFormat::Dot),
+            "mermaid" => Some(GraphFormat::Mermaid),
+            _ => None,
+        }
+    }
+}
+
+/// Which box everything goes in. Gates are numbered by where they are in the input
+#[derive(Default)]
+struct Cluster<'a> {
+    gates: Vec<usize>,
+    wires: Vec<&'a str>,
+}
+
+struct Layout<'a> {
+    gates: &'a [GateOp],
+    /// by bit, None holds whatever couldn't be placed
+    clusters: BTreeMap<Option<usize>, Cluster<'a>>,
+    suspicious: HashMap<&'a str, &'static str>,
+}
+
+impl<'a> Layout<'a> {
+    fn new(wire_gates: &'a WireGates) -> Self {
+        let bits = wire_bits(wire_gates);
+
+        let mut wires = BTreeSet::new();
+        wires.extend(wire_gates.wire_values.keys().map(String::as_str));
+        for gate in &wire_gates.gate_ops {
+            wires.extend(gate.inputs().map(String::as_str));
+            wires.insert(gate.wire_destination.as_str());
+        }
+
+        let mut clusters: BTreeMap<Option<usize>, Cluster> = BTreeMap::new();
+        for wire in wires {
+            clusters.entry(bits.get(wire).copied()).or_default().wires.push(wire);
+        }
+        // a gate sits with the wire it drives
+        for (index, gate) in wire_gates.gate_ops.iter().enumerate() {
+            let bit = bits.get(gate.wire_destination.as_str()).copied();
+            clusters.entry(bit).or_default().gates.push(index);
+        }
+
+        let suspicious = wire_gates.find_adder_faults()
+            .into_iter()
+            .filter_map(|fault| {
+                // faults own their wire names, point back at the gate's copy so it lives long enough
+                let gate = wire_gates.gate_ops.iter().find(|gate| gate.wire_destination == fault.wire)?;
+                Some((gate.wire_destination.as_str(), fault.reason))
+            })
+            .collect();
+
+        Layout {
+            gates: &wire_gates.gate_ops,
+            clusters,
+            suspicious,
+        }
+    }
+}
+
+/// x and y wires are their own bit, every gate's output is the highest bit going into it, which puts each full
+/// adder's five gates in the same bit even when some outputs are swapped. Empty when the circuit loops, it all goes
+/// in one big pile then
+fn wire_bits(wire_gates: &WireGates) -> HashMap<&str, usize> {
+    let mut bits: HashMap<&str, usize> = wire_gates.wire_values.keys()
+        .filter_map(|wire| Some((wire.as_str(), input_wire_bit(wire)?)))
+        .collect();
+
+    let Ok(order) = wire_gates.evaluation_order() else {
+        return HashMap::new();
+    };
+
+    for gate in order {
+        let bit = gate.inputs()
+            .filter_map(|wire| bits.get(wire.as_str()))
+            .max();
+        if let Some(&bit) = bit {
+            bits.insert(&gate.wire_destination, bit);
+        }
+    }
+
+    bits
+}
+
+pub(crate) fn render(wire_gates: &WireGates, format: GraphFormat) -> String {
+    let layout = Layout::new(wire_gates);
+
+    match format {
+        GraphFormat::Dot => to_dot(&layout),
+        GraphFormat::Mermaid => to_mermaid(&layout),
+    }
+}
+
+fn gate_colour(operation: &Operation) -> &'static str {
+    match operation {
+        Operation::And => "lightgreen",
+        Operation::Or => "lightpink",
+        Operation::Xor => "yellow",
+        Operation::Nand => "darkseagreen",
+        Operation::Nor => "plum",
+        Operation::Xnor => "khaki",
+        Operation::Not => "lightblue",
+    }
+}
+
+fn to_dot(layout: &Layout) -> String {
+    let mut dot = String::from("digraph circuit {\n  rankdir=LR;\n  node [fontname=\"monospace\"];\n");
+
+    for (bit, cluster) in &layout.clusters {
+        let indent = match bit {
+            Some(bit) => {
+                writeln!(dot, "  subgraph cluster_bit_{:02} {{\n    label=\"bit {:02}\";", bit, bit).unwrap();
+                "    "
+            }
+            None => "  ",
+        };
+
+        for wire in &cluster.wires {
+            match layout.suspicious.get(wire) {
+                Some(reason) => writeln!(dot, "{}\"{}\" [shape=ellipse, style=filled, fillcolor=red, penwidth=2, tooltip=\"{}\"];",
+                                         indent, wire, reason).unwrap(),
+                None => writeln!(dot, "{}\"{}\" [shape=ellipse];", indent, wire).unwrap(),
+            }
+        }
+        for &index in &cluster.gates {
+            let operation = &layout.gates[index].operation;
+            writeln!(dot, "{}\"g{}\" [shape=box, style=filled, fillcolor={}, label=\"{}\"];",
+                     indent, index, gate_colour(operation), operation).unwrap();
+        }
+
+        if bit.is_some() {
+            dot.push_str("  }\n");
+        }
+    }
+
+    for (index, gate) in layout.gates.iter().enumerate() {
+        for input in gate.inputs() {
+            writeln!(dot, "  \"{}\" -> \"g{}\";", input, index).unwrap();
+        }
+        writeln!(dot, "  \"g{}\" -> \"{}\";", index, gate.wire_destination).unwrap();
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+/// Mermaid ids get a prefix since wire names like "end" are keywords there
+fn to_mermaid(layout: &Layout) -> String {
+    let mut mermaid = String::from("flowchart LR\n");
+
+    for (bit, cluster) in &layout.clusters {
+        let indent = match bit {
+            Some(bit) => {
+                writeln!(mermaid, "  subgraph bit_{:02} [bit {:02}]", bit, bit).unwrap();
+                "    "
+            }
+            None => "  ",
+        };
+
+        for wire in &cluster.wires {
+            writeln!(mermaid, "{}w_{}([{}])", indent, wire, wire).unwrap();
+        }
+        for &index in &cluster.gates {
+            writeln!(mermaid, "{}g_{}[{}]", indent, index, layout.gates[index].operation).unwrap();
+        }
+
+        if bit.is_some() {
+            mermaid.push_str("  end\n");
+        }
+    }
+
+    for (index, gate) in layout.gates.iter().enumerate() {
+        for input in gate.inputs() {
+            writeln!(mermaid, "  w_{} --> g_{}", input, index).unwrap();
+        }
+        writeln!(mermaid, "  g_{} --> w_{}", index, gate.wire_destination).unwrap();
+    }
+
+    for operation in Operation::ALL {
+        let gates = layout.gates.iter()
+            .enumerate()
+            .filter(|(_, gate)| gate.operation == operation)
+            .map(|(index, _)| format!("g_{}", index))
+            .collect::<Vec<_>>();
+        if !gates.is_empty() {
+            let class = operation.to_string().to_lowercase();
+            writeln!(mermaid, "  classDef {} fill:{}", class, gate_colour(&operation)).unwrap();
+            writeln!(mermaid, "  class {} {}", gates.join(","), class).unwrap();
+        }
+    }
+
+    if !layout.suspicious.is_empty() {
+        let mut wires = layout.suspicious.keys()
+            .map(|wire| format!("w_{}", wire))
+            .collect::<Vec<_>>();
+        wires.sort();
+        mermaid.push_str("  classDef suspicious fill:red,stroke:darkred,stroke-width:3px\n");
+        writeln!(mermaid, "  class {} suspicious", wires.join(",")).unwrap();
+    }
+
+    mermaid
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // a 2 bit adder with bit 1's sum and carry AND swapped
+    const SWAPPED: &str = "\
+x00: 1
+x01: 0
+y00: 1
+y01: 1
+
+x00 XOR y00 -> z00
+y00 AND x00 -> c00
+x01 XOR y01 -> a01
+x01 AND y01 -> s01
+c00 XOR s01 -> z01
+s01 AND c00 -> b01
+a01 OR b01 -> z02
+";
+
+    fn wire_gates(circuit: &str) -> WireGates {
+        WireGates::parse(circuit).unwrap()
+    }
+
+    #[test]
+    fn gates_and_wires_are_separate_nodes() {
+        let dot = render(&wire_gates(SWAPPED), GraphFormat::Dot);
+
+        assert!(dot.contains("\"g2\" [shape=box, style=filled, fillcolor=yellow, label=\"XOR\"];"));
+        assert!(dot.contains("  \"x01\" -> \"g2\";\n  \"y01\" -> \"g2\";\n  \"g2\" -> \"a01\";\n"));
+        // only real connections, no x00 -> x01 chain
+        assert!(!dot.contains("\"x00\" -> \"x01\""));
+        assert_eq!(dot.matches(" -> ").count(), 7 * 3);
+    }
+
+    #[test]
+    fn each_bit_gets_a_cluster() {
+        let dot = render(&wire_gates(SWAPPED), GraphFormat::Dot);
+
+        let bit_1 = dot.split("subgraph cluster_bit_01 {").nth(1).unwrap();
+        let bit_1 = &bit_1[..bit_1.find("  }").unwrap()];
+        for node in ["x01", "y01", "a01", "s01", "b01", "z01", "z02", "g2", "g3", "g4", "g5", "g6"] {
+            assert!(bit_1.contains(&format!("\"{}\" [", node)), "{} isn't in bit 1", node);
+        }
+        assert!(!bit_1.contains("\"c00\""));
+    }
+
+    #[test]
+    fn suspicious_wires_are_highlighted() {
+        let wire_gates = wire_gates(SWAPPED);
+        let dot = render(&wire_gates, GraphFormat::Dot);
+        let mermaid = render(&wire_gates, GraphFormat::Mermaid);
+
+        assert!(dot.contains("\"a01\" [shape=ellipse, style=filled, fillcolor=red, penwidth=2, tooltip=\"x XOR y has to feed"));
+        assert!(dot.contains("\"s01\" [shape=ellipse, style=filled, fillcolor=red, penwidth=2, tooltip=\"an AND has to feed"));
+        assert!(dot.contains("\"z01\" [shape=ellipse];"));
+        assert!(mermaid.contains("  class w_a01,w_s01 suspicious\n"));
+    }
+
+    #[test]
+    fn mermaid() {
+        let mermaid = render(&wire_gates(SWAPPED), GraphFormat::Mermaid);
+
+        assert!(mermaid.starts_with("flowchart LR\n  subgraph bit_00 [bit 00]\n    w_c00([c00])\n"));
+        assert!(mermaid.contains("  w_x01 --> g_2\n  w_y01 --> g_2\n  g_2 --> w_a01\n"));
+        assert!(mermaid.contains("  class g_0,g_2,g_4 xor\n"));
+        assert_eq!(mermaid.matches("subgraph").count(), mermaid.matches("  end\n").count());
+    }
+
+    #[test]
+    fn loops_still_draw() {
+        let looped = SWAPPED.replace("-> c00", "-> tmp").replace("-> b01", "-> c00").replace("-> tmp", "-> b01");
+        let dot = render(&wire_gates(&looped), GraphFormat::Dot);
+
+        assert!(!dot.contains("cluster"));
+        assert_eq!(dot.matches(" -> ").count(), 7 * 3);
+    }
+
+    #[test]
+    fn only_x_and_y_wires_start_a_bit() {
+        let circuit = "\
+x00: 1
+y00: 1
+n05: 1
+x0a: 1
+
+x00 AND n05 -> z00
+x0a OR y00 -> z01
+";
+        let wire_gates = wire_gates(circuit);
+        let bits = wire_bits(&wire_gates);
+
+        assert_eq!(bits.get("n05"), None);
+        assert_eq!(bits.get("x0a"), None);
+        assert_eq!(bits.get("z00"), Some(&0));
+        assert_eq!(bits.get("z01"), Some(&0));
+    }
+
+    #[test]
+    fn format_names() {
+        assert_eq!(GraphFormat::from_name("dot"), Some(GraphFormat::Dot));
+        assert_eq!(GraphFormat::from_name("mermaid"), Some(GraphFormat::Mermaid));
+        assert_eq!(GraphFormat::from_name("svg"), None);
+    }
+}
@@ -0,0 +1,947 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use aoc_core::{Lcg, Solution};
+
+pub use graph::GraphFormat;
+pub use netlist::ParseError;
+
+mod graph;
+mod netlist;
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Parsed = WireGates;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        read_circuit(input)
+    }
+
+    fn part1(&self, wire_gates: &Self::Parsed) -> String {
+        solve_part_1(wire_gates).to_string()
+    }
+
+    fn part2(&self, wire_gates: &Self::Parsed) -> String {
+        solve_part_2(wire_gates)
+    }
+}
+
+fn read_circuit(input: &str) -> WireGates {
+    WireGates::parse(input).unwrap_or_else(|err| panic!("Expected a netlist, {}", err))
+}
+
+fn solve_part_1(wire_gates: &WireGates) -> u64 {
+    let evaluation = wire_gates.evaluate()
+        .unwrap_or_else(|err| panic!("Circuit can't be evaluated: {}", err));
+
+    get_wire_number(&evaluation.values, 'z')
+        .unwrap_or_else(|err| panic!("Expected the z wires to make a number: {}", err))
+}
+
+/// The circuit is meant to be a ripple carry adder with four pairs of gate outputs swapped, the answer is the eight
+/// swapped wires sorted and joined with commas. The structure rules say which wires are out of place, then pairing
+/// them up until the adder really adds confirms it
+fn solve_part_2(wire_gates: &WireGates) -> String {
+    let faults = wire_gates.find_adder_faults();
+
+    let suspicious = faults.iter()
+        .map(|fault| fault.wire.clone())
+        .collect::<Vec<_>>();
+    let swaps = wire_gates.find_fixing_swaps(&suspicious)
+        .unwrap_or_else(|| panic!("Expected {} to pair up into swaps that fix the adder", suspicious.join(",")));
+
+    let mut swapped = swaps.iter()
+        .flat_map(|swap| [swap.wire1.as_str(), swap.wire2.as_str()])
+        .collect::<Vec<_>>();
+    swapped.sort();
+    swapped.join(",")
+}
+
+
+/// The puzzle input's circuit drawn as DOT or Mermaid, with the wires find_adder_faults flags in red
+pub fn circuit_graph(input: &str, format: GraphFormat) -> Result<String, ParseError> {
+    Ok(graph::render(&WireGates::parse(input)?, format))
+}
+
+/// The puzzle input with part 2's swaps undone, in the same format so it can be fed back in
+pub fn repaired_netlist(input: &str) -> Result<String, String> {
+    let mut wire_gates = WireGates::parse(input).map_err(|err| err.to_string())?;
+    let suspicious = wire_gates.find_adder_faults()
+        .into_iter()
+        .map(|fault| fault.wire)
+        .collect::<Vec<_>>();
+    let swaps = wire_gates.find_fixing_swaps(&suspicious)
+        .ok_or_else(|| format!("{} don't pair up into swaps that fix the adder", suspicious.join(",")))?;
+
+    wire_gates.apply_swaps(&swaps)?;
+    Ok(netlist::write(&wire_gates))
+}
+
+/// Reads the `prefix` wires as a binary number, the wire number is the bit, so z00 is the lowest bit
+fn get_wire_number(map: &HashMap<String, usize>, prefix: char) -> Result<u64, CircuitError> {
+    get_wide_wire_number(map, prefix, 64).map(|number| number as u64)
+}
+
+/// get_wire_number for up to `width` bits, which can't be more than 128. A 64 bit adder's sum needs 65
+fn get_wide_wire_number(map: &HashMap<String, usize>, prefix: char, width: usize) -> Result<u128, CircuitError> {
+    let mut number = 0;
+    let mut too_wide = Vec::new();
+    for (wire, &value) in map {
+        match wire_bit(wire, prefix) {
+            Some(bit) if bit < width => number |= (value as u128) << bit,
+            Some(_) => too_wide.push(wire.clone()),
+            None => {}
+        }
+    }
+
+    if !too_wide.is_empty() {
+        return Err(CircuitError::TooWide(sorted_unique(too_wide)));
+    }
+    Ok(number)
+}
+
+/// The bit a numbered wire stands for, z07 is bit 7 of z. None for every other wire, names like `zed` or `x_carry`
+/// are just names
+fn wire_bit(wire: &str, prefix: char) -> Option<usize> {
+    let digits = wire.strip_prefix(prefix)?;
+    if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+        return None;
+    }
+
+    // too many digits for a usize is still a bit, just one that's way out of range
+    Some(digits.parse().unwrap_or(usize::MAX))
+}
+
+/// Random sums check_adder tries on top of the per bit ones when confirming a swap set
+pub const FUZZ_ROUNDS: usize = 200;
+pub const FUZZ_SEED: u64 = 2024;
+
+/// A parsed netlist. Besides solving the puzzle it can be rewired and run on any x and y, which is what `aoc adder`
+/// uses to try out swaps by hand
+#[derive(Debug, Clone)]
+pub struct WireGates {
+    wire_values: HashMap<String, usize>,
+    gate_ops: Vec<GateOp>
+}
+
+impl WireGates {
+    /// Wire values, a blank line, then the gates, see netlist for the details
+    pub fn parse(input: &str) -> Result<Self, ParseError> {
+        netlist::parse(input)
+    }
+
+    /// Orders the gates so every gate comes after the gates driving its inputs (Kahn's algorithm), then runs them in
+    /// that order. Fails instead of spinning if a wire is driven twice, never driven, or the gates loop back on
+    /// themselves
+    pub fn evaluate(&self) -> Result<Evaluation<'_>, CircuitError> {
+        let order = self.evaluation_order()?;
+
+        let mut values = self.wire_values.clone();
+        for &gate in &order {
+            let left_value = values[&gate.left_operand];
+            // NOT only reads the left
+            let right_value = gate.right_operand.as_ref().map_or(left_value, |wire| values[wire]);
+            let destination_value = Operation::perform_op(&left_value, &right_value, &gate.operation);
+
+            values.insert(gate.wire_destination.clone(), destination_value);
+        }
+
+        Ok(Evaluation { values, order })
+    }
+
+    fn evaluation_order(&self) -> Result<Vec<&GateOp>, CircuitError> {
+        // which gate drives each wire, the initial values drive themselves
+        let mut drivers: HashMap<&str, usize> = HashMap::new();
+        let mut multiply_driven = Vec::new();
+        for (index, gate) in self.gate_ops.iter().enumerate() {
+            let destination = gate.wire_destination.as_str();
+            if self.wire_values.contains_key(destination) || drivers.insert(destination, index).is_some() {
+                multiply_driven.push(destination.to_string());
+            }
+        }
+        if !multiply_driven.is_empty() {
+            return Err(CircuitError::MultiplyDriven(sorted_unique(multiply_driven)));
+        }
+
+        let undriven = self.gate_ops.iter()
+            .flat_map(GateOp::inputs)
+            .filter(|wire| !self.wire_values.contains_key(*wire) && !drivers.contains_key(wire.as_str()))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !undriven.is_empty() {
+            return Err(CircuitError::Undriven(sorted_unique(undriven)));
+        }
+
+        // how many inputs each gate is still waiting on, and who's waiting on each gate
+        let mut waiting_on = vec![0; self.gate_ops.len()];
+        let mut readers: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (index, gate) in self.gate_ops.iter().enumerate() {
+            for operand in gate.inputs() {
+                if drivers.contains_key(operand.as_str()) {
+                    waiting_on[index] += 1;
+                    readers.entry(operand).or_default().push(index);
+                }
+            }
+        }
+
+        let mut ready: VecDeque<usize> = (0..self.gate_ops.len())
+            .filter(|&index| waiting_on[index] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.gate_ops.len());
+
+        while let Some(index) = ready.pop_front() {
+            let gate = &self.gate_ops[index];
+            order.push(gate);
+
+            for &reader in readers.get(gate.wire_destination.as_str()).into_iter().flatten() {
+                waiting_on[reader] -= 1;
+                if waiting_on[reader] == 0 {
+                    ready.push_back(reader);
+                }
+            }
+        }
+
+        if order.len() < self.gate_ops.len() {
+            return Err(CircuitError::Cycle(self.find_cycle(&drivers, &waiting_on)));
+        }
+
+        Ok(order)
+    }
+
+    /// Every gate the sort couldn't finish is waiting on another unfinished gate, so walking back through those always
+    /// comes round to a gate we've already seen. Returns the wires round that loop, starting from the smallest
+    fn find_cycle(&self, drivers: &HashMap<&str, usize>, waiting_on: &[usize]) -> Vec<String> {
+        let unfinished = |wire: &String| drivers.get(wire.as_str()).filter(|&&index| waiting_on[index] > 0).copied();
+
+        let mut gate = waiting_on.iter().position(|&waiting| waiting > 0)
+            .expect("Expected an unfinished gate when looking for a cycle");
+        let mut walked = Vec::new();
+        let mut seen = HashSet::new();
+
+        while seen.insert(gate) {
+            walked.push(gate);
+            let gate_op = &self.gate_ops[gate];
+            gate = gate_op.inputs()
+                .find_map(unfinished)
+                .expect("Expected an unfinished gate to be waiting on another unfinished gate");
+        }
+
+        let loop_start = walked.iter().position(|&walked_gate| walked_gate == gate).unwrap();
+        // walking went from reader to driver, flip it so each wire feeds the next
+        let mut cycle = walked[loop_start..].iter()
+            .rev()
+            .map(|&index| self.gate_ops[index].wire_destination.clone())
+            .collect::<Vec<_>>();
+
+        let smallest = (0..cycle.len()).min_by_key(|&index| &cycle[index]).unwrap();
+        cycle.rotate_left(smallest);
+        cycle
+    }
+
+    /// How many bits wide x is, going by its highest wire so a missing bit in the middle still counts
+    fn input_bits(&self) -> usize {
+        self.wire_values.keys()
+            .filter_map(|wire| wire_bit(wire, 'x'))
+            .map(|bit| bit.saturating_add(1))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Fails if there's an x or y wire past bit 63, there's no u64 to set it from
+    fn check_input_width(&self) -> Result<(), CircuitError> {
+        let too_wide = self.wire_values.keys()
+            .filter(|wire| input_wire_bit(wire).is_some_and(|bit| bit >= 64))
+            .cloned()
+            .collect::<Vec<_>>();
+        if !too_wide.is_empty() {
+            return Err(CircuitError::TooWide(sorted_unique(too_wide)));
+        }
+
+        Ok(())
+    }
+
+    /// Sets the x and y wires to the bits of `x` and `y`, anything wider than the wires is dropped. Fails if there's
+    /// an x or y wire past bit 63
+    pub fn set_inputs(&mut self, x: u64, y: u64) -> Result<(), CircuitError> {
+        self.check_input_width()?;
+
+        let input_bit = |wire: &str| wire_bit(wire, 'x').map(|bit| (x, bit))
+            .or_else(|| wire_bit(wire, 'y').map(|bit| (y, bit)));
+        for (wire, value) in self.wire_values.iter_mut() {
+            if let Some((number, bit)) = input_bit(wire) {
+                *value = (number >> bit) as usize & 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Swaps the outputs of the gates driving each pair of wires
+    pub fn apply_swaps(&mut self, swaps: &[WireSwap]) -> Result<(), String> {
+        for swap in swaps {
+            let driver = |wire: &str| self.gate_ops.iter()
+                .position(|gate| gate.wire_destination == wire)
+                .ok_or_else(|| format!("no gate drives {}", wire));
+            let (first, second) = (driver(&swap.wire1)?, driver(&swap.wire2)?);
+
+            self.gate_ops[first].wire_destination = swap.wire2.clone();
+            self.gate_ops[second].wire_destination = swap.wire1.clone();
+        }
+
+        Ok(())
+    }
+
+    /// Runs the circuit with x and y set and reads z back
+    pub fn add(&self, x: u64, y: u64) -> Result<u64, CircuitError> {
+        get_wire_number(&self.run(x, y)?, 'z')
+    }
+
+    fn run(&self, x: u64, y: u64) -> Result<HashMap<String, usize>, CircuitError> {
+        let mut circuit = self.clone();
+        circuit.set_inputs(x, y)?;
+        let evaluation = circuit.evaluate()?;
+
+        Ok(evaluation.values)
+    }
+
+    /// Checks z == x + y for every bit on its own, every bit with a carry coming in, and then `random_rounds` random
+    /// pairs. Every swapped wire sits on some bit, so the per bit inputs catch most bad swaps and the random ones catch
+    /// what's left of the carry chain. Stops at the first sum that's wrong
+    pub fn check_adder(&self, random_rounds: usize, seed: u64) -> Result<(), AdderMismatch> {
+        let bits = self.input_bits();
+        // the per bit sums below can't even be written down past 64 bits
+        self.check_input_width().map_err(AdderMismatch::Broken)?;
+
+        let per_bit = (0..bits).flat_map(|bit| {
+            let one = 1u64 << bit;
+            // on its own in x and y, both at once to carry out of it, and a carry rippling in from below
+            [(one, 0), (0, one), (one, one), (one | (one - 1), 1)]
+        });
+        let mut rng = Lcg::new(seed);
+        let random = (0..random_rounds).map(move |_| (rng.bits(bits as u32), rng.bits(bits as u32)));
+
+        // a 64 bit sum carries into z64, so z is read a bit wider than x and y
+        for (x, y) in per_bit.chain(random) {
+            let z = self.run(x, y)
+                .and_then(|values| get_wide_wire_number(&values, 'z', bits + 1))
+                .map_err(AdderMismatch::Broken)?;
+            if z != x as u128 + y as u128 {
+                return Err(AdderMismatch::WrongSum { x, y, z });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Tries every way of pairing up `wires` until the swaps pass check_adder. There are only 105 ways to pair 8 wires
+    /// and a wrong pairing usually gives a wrong sum on the first few bits
+    fn find_fixing_swaps(&self, wires: &[String]) -> Option<Vec<WireSwap>> {
+        let Some((first, rest)) = wires.split_first() else {
+            let fixed = self.check_adder(FUZZ_ROUNDS, FUZZ_SEED);
+            return fixed.is_ok().then(Vec::new);
+        };
+
+        for (index, second) in rest.iter().enumerate() {
+            let swap = WireSwap::new(first, second);
+            let mut swapped = self.clone();
+            if swapped.apply_swaps(std::slice::from_ref(&swap)).is_err() {
+                continue;
+            }
+
+            let remaining = [&rest[..index], &rest[index + 1..]].concat();
+            if let Some(mut swaps) = swapped.find_fixing_swaps(&remaining) {
+                swaps.insert(0, swap);
+                return Some(swaps);
+            }
+        }
+
+        None
+    }
+
+    /// Checks every gate against where it would sit in a ripple carry adder. Bit 0 is a half adder, every other bit i
+    /// is a full adder:
+    ///
+    /// ```text
+    /// xi XOR yi -> sum         xi AND yi -> carry_a
+    /// sum XOR carry_in -> zi   sum AND carry_in -> carry_b
+    /// carry_a OR carry_b -> carry_out
+    /// ```
+    ///
+    /// and the last carry out is the top z. Swapping two outputs puts a wire somewhere its gate or the gates reading it
+    /// don't fit, so each rule below flags the wire rather than the gate. Sorted by wire
+    pub fn find_adder_faults(&self) -> Vec<AdderFault> {
+        let last_z = self.gate_ops.iter()
+            .map(|gate| &gate.wire_destination)
+            .filter(|wire| wire_bit(wire, 'z').is_some())
+            .max_by_key(|wire| wire_bit(wire, 'z'));
+
+        // what kind of gate reads each wire
+        let mut readers: HashMap<&str, Vec<&Operation>> = HashMap::new();
+        for gate in &self.gate_ops {
+            for input in gate.inputs() {
+                readers.entry(input).or_default().push(&gate.operation);
+            }
+        }
+        let feeds = |wire: &str, operation: Operation| {
+            readers.get(wire).is_some_and(|ops| ops.iter().any(|op| **op == operation))
+        };
+
+        let mut faults = Vec::new();
+        for gate in &self.gate_ops {
+            let destination = &gate.wire_destination;
+            let reads_inputs = gate.inputs().all(|wire| is_input_wire(wire));
+            let first_bit = reads_inputs && input_wire_bit(&gate.left_operand) == Some(0);
+            let is_z = wire_bit(destination, 'z').is_some();
+
+            let reason = match gate.operation {
+                _ if Some(destination) == last_z => (gate.operation != Operation::Or)
+                    .then_some("the last z is the final carry so it has to come from an OR"),
+                Operation::Xor if first_bit => (wire_bit(destination, 'z') != Some(0))
+                    .then_some("x00 XOR y00 is the first sum bit so it has to be z00"),
+                Operation::Xor if reads_inputs && is_z => Some("x XOR y is only half the sum past bit 0 so it can't be a z"),
+                Operation::Xor if !reads_inputs && !is_z => Some("an XOR that doesn't read x and y has to output a z"),
+                Operation::Xor if is_z => None,
+                Operation::Xor if !feeds(destination, Operation::Xor) => Some("x XOR y has to feed the XOR that makes the sum bit"),
+                _ if is_z => Some("every z but the last is a sum bit so it has to come from an XOR"),
+                // the first carry goes straight into bit 1 without an OR
+                Operation::And if first_bit => None,
+                Operation::And if !feeds(destination, Operation::Or) => Some("an AND has to feed the OR that makes the carry"),
+                Operation::Or if !feeds(destination, Operation::Xor) => Some("a carry has to feed the next bit's XOR"),
+                _ => None,
+            };
+
+            if let Some(reason) = reason {
+                faults.push(AdderFault {
+                    wire: destination.clone(),
+                    reason,
+                });
+            }
+        }
+
+        faults.sort_by(|a, b| a.wire.cmp(&b.wire));
+        faults
+    }
+}
+
+/// Every wire's value after running the circuit, and the gates in the order they ran
+#[derive(Debug)]
+pub struct Evaluation<'a> {
+    values: HashMap<String, usize>,
+    order: Vec<&'a GateOp>,
+}
+
+impl Evaluation<'_> {
+    /// One line per gate in the order they ran, with the values that went in and came out
+    pub fn trace(&self) -> String {
+        self.order.iter()
+            .map(|gate| {
+                let inputs = gate.inputs()
+                    .map(|wire| self.values[wire].to_string())
+                    .collect::<Vec<_>>();
+                format!("{}    {} {} = {}", gate, gate.operation, inputs.join(" "), self.values[&gate.wire_destination])
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Why a circuit can't be evaluated, each naming the wires at fault
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CircuitError {
+    /// Wires feeding back into themselves, each one feeds the next and the last feeds the first
+    Cycle(Vec<String>),
+    /// Wires some gate reads that have no initial value and no gate writing them
+    Undriven(Vec<String>),
+    /// Wires written by more than one gate, or by a gate and an initial value
+    MultiplyDriven(Vec<String>),
+    /// Numbered x, y or z wires past bit 63, too wide to set or read as a u64
+    TooWide(Vec<String>),
+}
+
+impl fmt::Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CircuitError::Cycle(wires) => write!(f, "wires loop back on themselves: {} -> {}", wires.join(" -> "), wires[0]),
+            CircuitError::Undriven(wires) => write!(f, "nothing drives {}", wires.join(", ")),
+            CircuitError::MultiplyDriven(wires) => write!(f, "more than one thing drives {}", wires.join(", ")),
+            CircuitError::TooWide(wires) => write!(f, "{} past bit 63, numbers are only 64 bits", wires.join(", ")),
+        }
+    }
+}
+
+impl std::error::Error for CircuitError {}
+
+fn sorted_unique(mut wires: Vec<String>) -> Vec<String> {
+    wires.sort();
+    wires.dedup();
+    wires
+}
+
+/// A wire find_adder_faults thinks was swapped, and the rule it broke
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdderFault {
+    pub wire: String,
+    pub reason: &'static str,
+}
+
+impl fmt::Display for AdderFault {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.wire, self.reason)
+    }
+}
+
+fn is_input_wire(wire: &str) -> bool {
+    input_wire_bit(wire).is_some()
+}
+
+fn input_wire_bit(wire: &str) -> Option<usize> {
+    wire_bit(wire, 'x').or_else(|| wire_bit(wire, 'y'))
+}
+
+/// Two gates trading output wires, which is how the puzzle broke the adder
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct WireSwap {
+    wire1: String,
+    wire2: String,
+}
+
+impl WireSwap {
+    pub fn new(wire1: &str, wire2: &str) -> Self {
+        WireSwap {
+            wire1: wire1.to_string(),
+            wire2: wire2.to_string(),
+        }
+    }
+
+    /// Pairs written `a:b` and separated by commas, `z05:a05,s10:a10`
+    pub fn parse_list(list: &str) -> Result<Vec<WireSwap>, String> {
+        list.split(',')
+            .map(|pair| match pair.trim().split_once(':') {
+                Some((wire1, wire2)) if !wire1.is_empty() && !wire2.is_empty() && wire1 != wire2 => {
+                    Ok(WireSwap::new(wire1.trim(), wire2.trim()))
+                }
+                _ => Err(format!("expected two different wires written a:b, got '{}'", pair)),
+            })
+            .collect()
+    }
+}
+
+/// Why check_adder thinks the circuit isn't an adder
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdderMismatch {
+    WrongSum { x: u64, y: u64, z: u128 },
+    Broken(CircuitError),
+}
+
+impl fmt::Display for AdderMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AdderMismatch::WrongSum { x, y, z } => write!(f, "{} + {} came out as {}", x, y, z),
+            AdderMismatch::Broken(err) => write!(f, "circuit can't be evaluated: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for AdderMismatch {}
+
+#[derive(Debug, Clone)]
+struct GateOp {
+    left_operand: String,
+    operation: Operation,
+    /// None for NOT
+    right_operand: Option<String>,
+    wire_destination: String
+}
+
+impl GateOp {
+    fn inputs(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.left_operand).chain(&self.right_operand)
+    }
+}
+
+impl fmt::Display for GateOp {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.right_operand {
+            Some(right_operand) => write!(f, "{} {} {} -> {}", self.left_operand, self.operation, right_operand, self.wire_destination),
+            None => write!(f, "{} {} -> {}", self.operation, self.left_operand, self.wire_destination),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    And,
+    Xor,
+    Or,
+    Nand,
+    Nor,
+    Xnor,
+    Not
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Operation::And => "AND",
+            Operation::Xor => "XOR",
+            Operation::Or => "OR",
+            Operation::Nand => "NAND",
+            Operation::Nor => "NOR",
+            Operation::Xnor => "XNOR",
+            Operation::Not => "NOT",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Operation {
+    const ALL: [Operation; 7] = [
+        Operation::And, Operation::Xor, Operation::Or, Operation::Nand, Operation::Nor, Operation::Xnor, Operation::Not,
+    ];
+
+    fn from_name(name: &str) -> Option<Operation> {
+        Operation::ALL.into_iter().find(|operation| operation.to_string() == name)
+    }
+
+    /// Wire values are only ever 0 or 1, so flipping the low bit is the negation. NOT ignores right_val
+    fn perform_op(left_val: &usize, right_val: &usize, operation: &Operation) -> usize {
+        match operation {
+            Operation::And => left_val & right_val,
+            Operation::Xor => left_val ^ right_val,
+            Operation::Or => left_val | right_val,
+            Operation::Nand => (left_val & right_val) ^ 1,
+            Operation::Nor => (left_val | right_val) ^ 1,
+            Operation::Xnor => (left_val ^ right_val) ^ 1,
+            Operation::Not => left_val ^ 1,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A correct ripple carry adder for `bits` bit numbers, all inputs 0. Wire names follow the bit so the tests can
+    /// pick gates to swap
+    fn ripple_carry_adder(bits: usize) -> String {
+        let mut wires = Vec::new();
+        let mut gates = vec![
+            String::from("x00 XOR y00 -> z00"),
+            String::from("y00 AND x00 -> c00"),
+        ];
+
+        for bit in 0..bits {
+            wires.push(format!("x{:02}: 0", bit));
+            wires.push(format!("y{:02}: 0", bit));
+        }
+
+        for bit in 1..bits {
+            let carry_in = format!("c{:02}", bit - 1);
+            let carry_out = if bit == bits - 1 { format!("z{:02}", bits) } else { format!("c{:02}", bit) };
+
+            gates.push(format!("x{:02} XOR y{:02} -> s{:02}", bit, bit, bit));
+            gates.push(format!("x{:02} AND y{:02} -> a{:02}", bit, bit, bit));
+            gates.push(format!("{} XOR s{:02} -> z{:02}", carry_in, bit, bit));
+            gates.push(format!("s{:02} AND {} -> b{:02}", bit, carry_in, bit));
+            gates.push(format!("a{:02} OR b{:02} -> {}", bit, bit, carry_out));
+        }
+
+        format!("{}\n\n{}\n", wires.join("\n"), gates.join("\n"))
+    }
+
+    fn swap_outputs(circuit: &str, first: &str, second: &str) -> String {
+        circuit.replace(&format!("-> {}", first), "-> SWAP")
+            .replace(&format!("-> {}", second), &format!("-> {}", first))
+            .replace("-> SWAP", &format!("-> {}", second))
+    }
+
+    fn evaluate(circuit: &str) -> Result<HashMap<String, usize>, CircuitError> {
+        read_circuit(circuit).evaluate().map(|evaluation| evaluation.values)
+    }
+
+    #[test]
+    fn part_1_small_example() {
+        let example = "\
+x00: 1
+x01: 1
+x02: 1
+y00: 0
+y01: 1
+y02: 0
+
+x00 AND y00 -> z00
+x01 XOR y01 -> z01
+x02 OR y02 -> z02
+";
+        assert_eq!(solve_part_1(&read_circuit(example)), 4);
+    }
+
+    #[test]
+    fn only_prefix_and_digits_are_bits() {
+        let circuit = "\
+x00: 1
+x: 1
+x_carry: 1
+y00: 1
+
+x00 AND y00 -> zed
+x XOR x_carry -> z00
+zed OR x -> z01
+";
+        assert_eq!(solve_part_1(&read_circuit(circuit)), 2);
+    }
+
+    #[test]
+    fn bits_past_63_are_errors() {
+        let circuit = "\
+x00: 1
+x64: 1
+y00: 0
+
+x00 OR y00 -> z00
+x64 AND y00 -> z64
+";
+        let too_wide = |wire: &str| CircuitError::TooWide(vec![String::from(wire)]);
+        assert_eq!(read_circuit(circuit).set_inputs(1, 1), Err(too_wide("x64")));
+        assert_eq!(read_circuit(circuit).add(1, 1), Err(too_wide("x64")));
+
+        let values = read_circuit(circuit).evaluate().unwrap().values;
+        assert_eq!(get_wire_number(&values, 'z'), Err(too_wide("z64")));
+        assert_eq!(too_wide("z64").to_string(), "z64 past bit 63, numbers are only 64 bits");
+    }
+
+    #[test]
+    fn part_1_example() {
+        let example = "\
+x00: 1
+x01: 0
+x02: 1
+x03: 1
+x04: 0
+y00: 1
+y01: 1
+y02: 1
+y03: 1
+y04: 1
+
+ntg XOR fgs -> mjb
+y02 OR x01 -> tnw
+kwq OR kpj -> z05
+x00 OR x03 -> fst
+tgd XOR rvg -> z01
+vdt OR tnw -> bfw
+bfw AND frj -> z10
+ffh OR nrd -> bqk
+y00 AND y03 -> djm
+y03 OR y00 -> psh
+bqk OR frj -> z08
+tnw OR fst -> frj
+gnj AND tgd -> z11
+bfw XOR mjb -> z00
+x03 OR x00 -> vdt
+gnj AND wpb -> z02
+x04 AND y00 -> kjc
+djm OR pbm -> qhw
+nrd AND vdt -> hwm
+kjc AND fst -> rvg
+y04 OR y02 -> fgs
+y01 AND x02 -> pbm
+ntg OR kjc -> kwq
+psh XOR fgs -> tgd
+qhw XOR tgd -> z09
+pbm OR djm -> kpj
+x03 XOR y03 -> ffh
+x00 XOR y04 -> ntg
+bfw OR bqk -> z06
+nrd XOR fgs -> wpb
+frj XOR qhw -> z04
+bqk OR frj -> z07
+y03 OR x01 -> nrd
+hwm AND bqk -> z03
+tgd XOR rvg -> z12
+tnw OR pbm -> gnj
+";
+        assert_eq!(solve_part_1(&read_circuit(example)), 2024);
+    }
+
+    #[test]
+    fn gates_run_after_their_inputs() {
+        let wire_gates = read_circuit(&ripple_carry_adder(4));
+        let evaluation = wire_gates.evaluate().unwrap();
+
+        assert_eq!(evaluation.order.len(), wire_gates.gate_ops.len());
+        let mut ready: HashSet<&str> = wire_gates.wire_values.keys().map(|wire| wire.as_str()).collect();
+        for gate in &evaluation.order {
+            assert!(gate.inputs().all(|wire| ready.contains(wire.as_str())),
+                    "{:?} ran before its inputs", gate);
+            ready.insert(&gate.wire_destination);
+        }
+    }
+
+    #[test]
+    fn reports_cycles() {
+        let circuit = "\
+x00: 1
+y00: 0
+
+x00 AND ccc -> aaa
+aaa XOR y00 -> bbb
+bbb OR x00 -> ccc
+aaa AND y00 -> z00
+";
+        let err = evaluate(circuit).unwrap_err();
+        assert_eq!(err, CircuitError::Cycle(vec![String::from("aaa"), String::from("bbb"), String::from("ccc")]));
+        assert_eq!(err.to_string(), "wires loop back on themselves: aaa -> bbb -> ccc -> aaa");
+    }
+
+    #[test]
+    fn reports_undriven_wires() {
+        let circuit = "\
+x00: 1
+
+x00 AND qqq -> z00
+ppp OR qqq -> z01
+";
+        assert_eq!(evaluate(circuit), Err(CircuitError::Undriven(vec![String::from("ppp"), String::from("qqq")])));
+    }
+
+    #[test]
+    fn reports_multiply_driven_wires() {
+        let circuit = "\
+x00: 1
+y00: 0
+
+x00 AND y00 -> z00
+x00 OR y00 -> z00
+x00 XOR y00 -> y00
+";
+        assert_eq!(evaluate(circuit), Err(CircuitError::MultiplyDriven(vec![String::from("y00"), String::from("z00")])));
+    }
+
+    #[test]
+    fn adds_any_inputs() {
+        let adder = read_circuit(&ripple_carry_adder(8));
+
+        assert_eq!(adder.input_bits(), 8);
+        assert_eq!(adder.add(5, 7), Ok(12));
+        assert_eq!(adder.add(255, 255), Ok(510));
+        // too wide for the inputs, only the low 8 bits go in
+        assert_eq!(adder.add(256 + 3, 4), Ok(7));
+    }
+
+    #[test]
+    fn correct_adder_passes_the_check() {
+        assert_eq!(read_circuit(&ripple_carry_adder(12)).check_adder(1000, 24), Ok(()));
+    }
+
+    #[test]
+    fn check_catches_swaps_and_swapping_back_fixes_them() {
+        let circuit = swap_outputs(&ripple_carry_adder(12), "s07", "a07");
+        let circuit = swap_outputs(&circuit, "z03", "c03");
+        let mut adder = read_circuit(&circuit);
+
+        assert!(matches!(adder.check_adder(1000, 24), Err(AdderMismatch::WrongSum { .. })));
+
+        adder.apply_swaps(&[WireSwap::new("a07", "s07"), WireSwap::new("c03", "z03")]).unwrap();
+        assert_eq!(adder.check_adder(1000, 24), Ok(()));
+    }
+
+    #[test]
+    fn checks_64_bit_adders_without_overflowing() {
+        assert_eq!(read_circuit(&ripple_carry_adder(64)).check_adder(50, 24), Ok(()));
+
+        let broken = swap_outputs(&ripple_carry_adder(64), "s63", "a63");
+        assert!(matches!(read_circuit(&broken).check_adder(50, 24), Err(AdderMismatch::WrongSum { .. })));
+
+        let too_wide = CircuitError::TooWide(vec![String::from("x64"), String::from("y64")]);
+        assert_eq!(read_circuit(&ripple_carry_adder(65)).check_adder(50, 24), Err(AdderMismatch::Broken(too_wide)));
+    }
+
+    #[test]
+    fn input_bits_go_by_the_highest_x() {
+        let circuit = "\
+x00: 1
+x05: 1
+y00: 0
+
+x00 AND y00 -> z00
+";
+        assert_eq!(read_circuit(circuit).input_bits(), 6);
+    }
+
+    #[test]
+    fn swap_lists() {
+        assert_eq!(WireSwap::parse_list("a07:s07, c03:z03"),
+                   Ok(vec![WireSwap::new("a07", "s07"), WireSwap::new("c03", "z03")]));
+        assert_eq!(WireSwap::parse_list("a07-s07"), Err(String::from("expected two different wires written a:b, got 'a07-s07'")));
+        assert_eq!(WireSwap::parse_list("a07:a07"), Err(String::from("expected two different wires written a:b, got 'a07:a07'")));
+        assert!(WireSwap::parse_list("a07:").is_err());
+    }
+
+    #[test]
+    fn swaps_that_loop_are_broken_not_wrong() {
+        let mut adder = read_circuit(&ripple_carry_adder(4));
+        // the carry into bit 2 now comes out of bit 2's sum, which reads that carry
+        adder.apply_swaps(&[WireSwap::new("c01", "z02")]).unwrap();
+
+        assert!(matches!(adder.check_adder(10, 24), Err(AdderMismatch::Broken(CircuitError::Cycle(_)))));
+        assert_eq!(adder.apply_swaps(&[WireSwap::new("c01", "nope")]), Err(String::from("no gate drives nope")));
+    }
+
+    #[test]
+    fn correct_adder_has_no_faults() {
+        assert_eq!(solve_part_2(&read_circuit(&ripple_carry_adder(5))), "");
+    }
+
+    #[test]
+    fn finds_swapped_wires() {
+        let circuit = ripple_carry_adder(5);
+        // a sum bit swapped with a carry, and a half sum swapped with the AND next to it
+        let circuit = swap_outputs(&circuit, "z02", "a02");
+        let circuit = swap_outputs(&circuit, "s03", "a03");
+
+        assert_eq!(solve_part_2(&read_circuit(&circuit)), "a02,a03,s03,z02");
+    }
+
+    #[test]
+    fn finds_a_half_sum_swapped_with_its_z() {
+        // x03 XOR y03 -> z03 reads the inputs and outputs a z, both of which look fine on their own
+        let circuit = swap_outputs(&ripple_carry_adder(5), "z03", "s03");
+
+        assert_eq!(read_circuit(&circuit).find_adder_faults(), vec![
+            AdderFault { wire: String::from("s03"), reason: "an XOR that doesn't read x and y has to output a z" },
+            AdderFault { wire: String::from("z03"), reason: "x XOR y is only half the sum past bit 0 so it can't be a z" },
+        ]);
+        assert_eq!(solve_part_2(&read_circuit(&circuit)), "s03,z03");
+    }
+
+    #[test]
+    fn puzzle_sized_adder() {
+        let mut circuit = ripple_carry_adder(45);
+        for (first, second) in [("z05", "a05"), ("s10", "a10"), ("z20", "c20"), ("z30", "b30")] {
+            circuit = swap_outputs(&circuit, first, second);
+        }
+
+        assert_eq!(solve_part_2(&read_circuit(&circuit)), "a05,a10,b30,c20,s10,z05,z20,z30");
+    }
+
+    #[test]
+    fn repairs_the_netlist() {
+        let original = ripple_carry_adder(6);
+        let swapped = swap_outputs(&swap_outputs(&original, "z02", "a02"), "s04", "a04");
+
+        assert_eq!(repaired_netlist(&swapped), Ok(netlist::write(&read_circuit(&original))));
+    }
+
+    #[test]
+    fn says_why() {
+        let circuit = swap_outputs(&ripple_carry_adder(3), "z01", "c01");
+        let faults = read_circuit(&circuit).find_adder_faults();
+
+        assert_eq!(faults, vec![
+            AdderFault { wire: String::from("c01"), reason: "an XOR that doesn't read x and y has to output a z" },
+            AdderFault { wire: String::from("z01"), reason: "every z but the last is a sum bit so it has to come from an XOR" },
+        ]);
+    }
+}
@@ -0,0 +1,265 @@
+//! Reading and writing the puzzle's netlist format:
+//!
+//! ```text
+//! x00: 1
+//! y00: 0
+//!
+//! x00 AND y00 -> z00
+//! NOT z00 -> z01
+//! ```
+//!
+//! wire values, a blank line, then one gate per line
+
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::{GateOp, Operation, WireGates};
+
+/// Lines and columns count from 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    column: usize,
+    text: &'a str,
+}
+
+/// Splits on whitespace, remembering the column each piece started at
+fn tokens(line: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (index, character) in line.char_indices().chain([(line.len(), ' ')]) {
+        match (character.is_whitespace(), start) {
+            (false, None) => start = Some(index),
+            (true, Some(from)) => {
+                tokens.push(Token { column: from + 1, text: &line[from..index] });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+
+    tokens
+}
+
+pub(crate) fn parse(input: &str) -> Result<WireGates, ParseError> {
+    let mut lines = input.lines().enumerate().map(|(index, line)| (index + 1, line));
+    let mut wire_values = HashMap::new();
+    let mut last_line = 0;
+
+    loop {
+        let Some((number, line)) = lines.next() else {
+            return Err(ParseError::new(last_line + 1, 1, "expected a blank line and then the gates"));
+        };
+        if line.trim().is_empty() {
+            break;
+        }
+
+        let (wire, value) = parse_wire_value(number, line)?;
+        if wire_values.insert(wire.text.to_string(), value).is_some() {
+            return Err(ParseError::new(number, wire.column, format!("{} already has a value", wire.text)));
+        }
+        last_line = number;
+    }
+
+    let mut gate_ops = Vec::new();
+    for (number, line) in lines {
+        // the file usually ends with an empty line or two
+        if !line.trim().is_empty() {
+            gate_ops.push(parse_gate(number, line)?);
+        }
+    }
+
+    Ok(WireGates { wire_values, gate_ops })
+}
+
+/// `x00: 1`
+fn parse_wire_value(number: usize, line: &str) -> Result<(Token<'_>, usize), ParseError> {
+    let Some((name, value)) = line.split_once(':') else {
+        let message = if line.contains("->") {
+            "expected `wire: value`, gates go after a blank line"
+        } else {
+            "expected `wire: value`"
+        };
+        return Err(ParseError::new(number, 1, message));
+    };
+
+    let wire = match tokens(name)[..] {
+        [wire] => wire_name(number, wire)?,
+        [] => return Err(ParseError::new(number, 1, "expected a wire name before ':'")),
+        [_, extra, ..] => return Err(ParseError::new(number, extra.column, "wire names can't have spaces in them")),
+    };
+
+    let value_column = name.len() + 2;
+    let value = match tokens(value)[..] {
+        [Token { text: "0", .. }] => 0,
+        [Token { text: "1", .. }] => 1,
+        [] => return Err(ParseError::new(number, value_column, "expected 0 or 1 after ':'")),
+        [token, ..] => return Err(ParseError::new(number, value_column + token.column - 1,
+                                                  format!("expected 0 or 1, found '{}'", token.text))),
+    };
+
+    Ok((wire, value))
+}
+
+/// `a AND b -> c`, or `NOT a -> c`
+fn parse_gate(number: usize, line: &str) -> Result<GateOp, ParseError> {
+    let mut tokens = tokens(line).into_iter();
+    let end = line.trim_end().len() + 1;
+    let mut next = |expected: &str| tokens.next()
+        .ok_or_else(|| ParseError::new(number, end, format!("expected {}", expected)));
+
+    let first = next("a gate")?;
+    let (operation, left_operand, right_operand) = if first.text == "NOT" {
+        let input = wire_name(number, next("the wire NOT reads")?)?;
+        (Operation::Not, input.text.to_string(), None)
+    } else {
+        let left = wire_name(number, first)?;
+        let name = next("a gate after the first wire")?;
+        let operation = Operation::from_name(name.text)
+            .ok_or_else(|| ParseError::new(number, name.column,
+                                           format!("unknown gate '{}', expected AND, OR, XOR, NAND, NOR, XNOR or NOT", name.text)))?;
+        if operation == Operation::Not {
+            return Err(ParseError::new(number, first.column, "NOT takes one wire, write it as `NOT a -> b`"));
+        }
+        let right = wire_name(number, next(&format!("the second wire {} reads", operation))?)?;
+        (operation, left.text.to_string(), Some(right.text.to_string()))
+    };
+
+    let arrow = next("'->'")?;
+    if arrow.text != "->" {
+        return Err(ParseError::new(number, arrow.column, format!("expected '->', found '{}'", arrow.text)));
+    }
+    let destination = wire_name(number, next("the wire the gate drives after '->'")?)?;
+
+    if let Some(extra) = tokens.next() {
+        return Err(ParseError::new(number, extra.column, format!("unexpected '{}' after the gate", extra.text)));
+    }
+
+    Ok(GateOp {
+        left_operand,
+        operation,
+        right_operand,
+        wire_destination: destination.text.to_string(),
+    })
+}
+
+fn wire_name(number: usize, token: Token) -> Result<Token, ParseError> {
+    if token.text == "->" {
+        return Err(ParseError::new(number, token.column, "expected a wire name before '->'"));
+    }
+
+    match token.text.char_indices().find(|(_, character)| !character.is_ascii_alphanumeric() && *character != '_') {
+        Some((index, character)) => Err(ParseError::new(number, token.column + index,
+                                                        format!("wire names are letters, digits and _, found '{}'", character))),
+        None => Ok(token),
+    }
+}
+
+/// Back to text that parse reads, wires sorted by name and gates in the order they came in
+pub(crate) fn write(wire_gates: &WireGates) -> String {
+    let mut wires = wire_gates.wire_values.iter().collect::<Vec<_>>();
+    wires.sort();
+
+    let mut netlist = String::new();
+    for (wire, value) in wires {
+        netlist.push_str(&format!("{}: {}\n", wire, value));
+    }
+    netlist.push('\n');
+    for gate in &wire_gates.gate_ops {
+        netlist.push_str(&format!("{}\n", gate));
+    }
+
+    netlist
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> String {
+        parse(input).unwrap_err().to_string()
+    }
+
+    #[test]
+    fn reads_every_gate_kind() {
+        let wire_gates = parse("\
+x00: 1
+y00: 0
+
+x00 AND y00 -> a
+x00 OR y00 -> b
+x00 XOR y00 -> c
+x00 NAND y00 -> d
+x00 NOR y00 -> e
+x00 XNOR y00 -> f
+NOT x00 -> g
+").unwrap();
+
+        let values = wire_gates.evaluate().unwrap().values;
+        let outputs = ["a", "b", "c", "d", "e", "f", "g"].map(|wire| values[wire]);
+        assert_eq!(outputs, [0, 1, 1, 1, 0, 0, 0]);
+        assert_eq!(wire_gates.gate_ops[6].right_operand, None);
+    }
+
+    #[test]
+    fn writes_back_what_it_read() {
+        let netlist = "\
+x00: 1
+x01: 0
+y00: 1
+
+x00 XNOR y00 -> abc
+NOT abc -> z00
+x01 AND abc -> z01
+";
+        assert_eq!(write(&parse(netlist).unwrap()), netlist);
+        // extra spaces and trailing blank lines don't survive
+        assert_eq!(write(&parse(&netlist.replace(" -> ", "   ->  ").replace(": ", ":")).unwrap()), netlist);
+    }
+
+    #[test]
+    fn points_at_the_problem() {
+        assert_eq!(error("x00: 1\ny00: 2\n\nx00 AND y00 -> z00\n"), "line 2, column 6: expected 0 or 1, found '2'");
+        assert_eq!(error("x00: 1\ny00:\n\nx00 AND y00 -> z00\n"), "line 2, column 5: expected 0 or 1 after ':'");
+        assert_eq!(error("x00: 1\nx00: 0\n\n"), "line 2, column 1: x00 already has a value");
+        assert_eq!(error("x00: 1\nx00 AND y00 -> z00\n"), "line 2, column 1: expected `wire: value`, gates go after a blank line");
+        assert_eq!(error("x00: 1\ny00: 0\n"), "line 3, column 1: expected a blank line and then the gates");
+        assert_eq!(error("x-0: 1\n\n"), "line 1, column 2: wire names are letters, digits and _, found '-'");
+
+        let gates = "x00: 1\ny00: 0\n\nx00 AND y00 -> z00\n";
+        assert_eq!(error(&gates.replace("AND", "NAD")), "line 4, column 5: unknown gate 'NAD', expected AND, OR, XOR, NAND, NOR, XNOR or NOT");
+        assert_eq!(error(&gates.replace("AND", "NOT")), "line 4, column 1: NOT takes one wire, write it as `NOT a -> b`");
+        assert_eq!(error(&gates.replace("-> z00", "=> z00")), "line 4, column 13: expected '->', found '=>'");
+        assert_eq!(error(&gates.replace(" -> z00", "")), "line 4, column 12: expected '->'");
+        assert_eq!(error(&gates.replace(" z00", "")), "line 4, column 15: expected the wire the gate drives after '->'");
+        assert_eq!(error(&gates.replace("z00", "z00 z01")), "line 4, column 20: unexpected 'z01' after the gate");
+        assert_eq!(error(&gates.replace("x00 AND y00", "NOT")), "line 4, column 5: expected a wire name before '->'");
+        assert_eq!(error(&gates.replace(" y00 ->", " ->")), "line 4, column 9: expected a wire name before '->'");
+        assert_eq!(error(&gates.replace("x00 AND y00 -> z00", "NOT")), "line 4, column 4: expected the wire NOT reads");
+    }
+}
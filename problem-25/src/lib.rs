@@ -1,24 +1,26 @@
-use std::io::BufRead;
-use std::time::{SystemTime, UNIX_EPOCH};
-
-mod gimme_input;
+use aoc_core::{sections, Solution};
 
 const COLUMN: char = '#';
-const SPACE: char = '.';
 const PIN_WIDTH: usize = 5;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Parsed = (Vec<Vec<usize>>, Vec<Vec<usize>>);
 
-    println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT));
+    // there's only the one puzzle on the last day
+    const HAS_PART_2: bool = false;
 
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
 
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+    fn part1(&self, keys_and_locks: &Self::Parsed) -> String {
+        solve_part_1(keys_and_locks).to_string()
+    }
 }
 
-fn solve_part_1(input: &str) -> u64 {
-    let keys_and_locks = parse_input(input);
+fn solve_part_1(keys_and_locks: &(Vec<Vec<usize>>, Vec<Vec<usize>>)) -> u64 {
     let keys = &keys_and_locks.0;
     let locks = &keys_and_locks.1;
 
@@ -34,7 +36,7 @@ fn solve_part_1(input: &str) -> u64 {
     fits
 }
 
-fn is_key_lock_fit(lock: &Vec<usize>, key: &Vec<usize>) -> bool {
+fn is_key_lock_fit(lock: &[usize], key: &[usize]) -> bool {
     let fits = true;
 
     for (index, val) in lock.iter().enumerate() {
@@ -50,11 +52,8 @@ fn parse_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
     let mut keys = Vec::new();
     let mut locks = Vec::new();
 
-    let keys_and_locks: Vec<&str> = input
-        .split("\n\n")                    // Split on double newlines
-        .collect();
-
-    for key_lock in keys_and_locks {
+    // keys and locks are separated by blank lines
+    for key_lock in sections(input) {
         if is_lock(key_lock) {
             locks.push(parse_key(key_lock));
         } else {
@@ -67,7 +66,7 @@ fn parse_input(input: &str) -> (Vec<Vec<usize>>, Vec<Vec<usize>>) {
 }
 
 fn is_lock(key_candidate: &str) -> bool {
-    let first_line = key_candidate.lines().nth(0).unwrap();
+    let first_line = key_candidate.lines().next().unwrap();
     first_line.chars().any(|c| c == COLUMN)
 }
 
@@ -109,3 +108,62 @@ fn parse_lock(lock_input: &str) -> Vec<usize> {
     lock
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+#####
+.####
+.####
+.####
+.#.#.
+.#...
+.....
+
+#####
+##.##
+.#.##
+...##
+...#.
+...#.
+.....
+
+.....
+#....
+#....
+#...#
+#.#.#
+#.###
+#####
+
+.....
+.....
+#.#..
+###..
+###.#
+###.#
+#####
+
+.....
+.....
+.....
+#....
+#.#..
+#.#.#
+#####
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 3);
+    }
+
+    #[test]
+    fn pin_heights() {
+        let (keys, locks) = parse_input(EXAMPLE);
+
+        assert_eq!(locks, vec![vec![0, 5, 3, 4, 3], vec![1, 2, 0, 5, 3]]);
+        assert_eq!(keys, vec![vec![5, 0, 2, 1, 3], vec![4, 3, 4, 0, 2], vec![3, 0, 2, 0, 1]]);
+    }
+}
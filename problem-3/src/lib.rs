@@ -0,0 +1,98 @@
+// problem statement: https://adventofcode.com/2024/day/3
+use aoc_core::Solution;
+use regex::Regex;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_instructions(input)
+    }
+
+    fn part1(&self, instructions: &Self::Parsed) -> String {
+        solve_part_1(instructions).to_string()
+    }
+
+    fn part2(&self, instructions: &Self::Parsed) -> String {
+        solve_part_2(instructions).to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+// working with capture groups in rust: https://docs.rs/regex/latest/regex/#example-named-capture-groups
+fn parse_instructions(input: &str) -> Vec<Instruction> {
+    let instruction_pattern: Regex = Regex::new(r"mul\((\d{1,3}),\s*(\d{1,3})\)|do\(\)|don't\(\)").unwrap();
+
+    instruction_pattern.captures_iter(input)
+        .map(|capture| match &capture[0] {
+            "do()" => Instruction::Do,
+            "don't()" => Instruction::Dont,
+            _ => {
+                let left_operand = capture[1].parse::<u64>().unwrap();
+                let right_operand = capture[2].parse::<u64>().unwrap();
+                Instruction::Mul(left_operand, right_operand)
+            }
+        })
+        .collect()
+}
+
+fn solve_part_1(instructions: &[Instruction]) -> u64 {
+    instructions.iter()
+        .map(|instruction| match instruction {
+            Instruction::Mul(left, right) => left * right,
+            _ => 0,
+        })
+        .sum()
+}
+
+// everything before the first don't() is enabled, after that only what follows a do() up to the next don't()
+fn solve_part_2(instructions: &[Instruction]) -> u64 {
+    let mut enabled = true;
+    let mut sum = 0;
+
+    for instruction in instructions {
+        match instruction {
+            Instruction::Mul(left, right) if enabled => sum += left * right,
+            Instruction::Mul(..) => {}
+            Instruction::Do => enabled = true,
+            Instruction::Dont => enabled = false,
+        }
+    }
+
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn part_1_example() {
+        let example = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
+        assert_eq!(solve_part_1(&parse_instructions(example)), 161);
+    }
+
+    #[test]
+    fn part_2_example() {
+        let example = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
+        assert_eq!(solve_part_2(&parse_instructions(example)), 48);
+    }
+
+    #[test]
+    fn part_2_leading_do_is_not_counted_twice() {
+        assert_eq!(solve_part_2(&parse_instructions("do()mul(2,3)don't()mul(4,5)")), 6);
+    }
+
+    #[test]
+    fn part_2_enabled_across_lines() {
+        assert_eq!(solve_part_2(&parse_instructions("mul(1,2)don't()\nmul(3,3)\ndo()mul(2,2)")), 6);
+    }
+}
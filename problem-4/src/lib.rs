@@ -0,0 +1,115 @@
+// https://adventofcode.com/2024/day/4
+
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Pos};
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed = Grid<char>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, grid: &Self::Parsed) -> String {
+        solve_part_1(grid).to_string()
+    }
+
+    fn part2(&self, grid: &Self::Parsed) -> String {
+        solve_part_2(grid).to_string()
+    }
+}
+
+fn solve_part_1(grid: &Grid<char>) -> i32 {
+    let mut found_xmas = 0;
+
+    // every X can start an XMAS in any of the 8 directions, backwards and diagonals included
+    for x_position in grid.find_all(&'X') {
+        for direction in Direction::ALL {
+            if recursive_bullshit(grid, x_position, direction, &mut String::from("X")) {
+                found_xmas += 1;
+            }
+        }
+    }
+
+    found_xmas
+}
+
+fn parse_input(input: &str) -> Grid<char> {
+    Grid::parse(input)
+}
+
+// recursively build XMAS, if we get XMAS, that's bingo-bango
+fn recursive_bullshit(grid: &Grid<char>,
+                      current_position: Pos,
+                      direction: Direction,
+                      xmas_progress: &mut String) -> bool {
+    if xmas_progress == "XMAS" {
+        return true;
+    }
+
+    let next_char = match xmas_progress.chars().last().unwrap() {
+        'X' => 'M',
+        'M' => 'A',
+        'A' => 'S',
+        _ => return false,
+    };
+
+    let next_position = current_position.next(direction);
+    if grid.get(next_position) == Some(&next_char) {
+        xmas_progress.push(next_char);
+
+        recursive_bullshit(grid, next_position, direction, xmas_progress)
+    } else {
+        false
+    }
+}
+
+fn solve_part_2(grid: &Grid<char>) -> u32 {
+    let mut found_xmas = 0;
+
+    // At every A, both diagonals through it need an M on one end and an S on the other
+    for a_position in grid.find_all(&'A') {
+        if is_mas_diagonal(grid, a_position, Direction::UpLeft) && is_mas_diagonal(grid, a_position, Direction::UpRight) {
+            found_xmas += 1;
+        }
+    }
+
+    found_xmas
+}
+
+fn is_mas_diagonal(grid: &Grid<char>, a_position: Pos, corner: Direction) -> bool {
+    let corner_char = grid.get(a_position.next(corner));
+    let opposite_char = grid.get(a_position.next(corner.opposite()));
+
+    matches!((corner_char, opposite_char), (Some('M'), Some('S')) | (Some('S'), Some('M')))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 18);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 9);
+    }
+}
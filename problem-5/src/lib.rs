@@ -1,27 +1,54 @@
 // https://adventofcode.com/2024/day/5
 
 use std::collections::{HashMap, HashSet};
-use std::time::{SystemTime, UNIX_EPOCH};
 
-mod gimme_input;
+use aoc_core::{sections, Solution};
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    // println!("PART 1: {}", solve_part_1(gimme_input::TEST_INPUT_RULES, gimme_input::TEST_INPUT_ORDER));
-    // println!("PART 1: {}", solve_part_1(gimme_input::INPUT_RULES, gimme_input::INPUT_ORDER));
-    //println!("PART 2: {}", solve_part_2(gimme_input::TEST_INPUT_RULES, gimme_input::TEST_INPUT_ORDER));
-    println!("PART 2: {}", solve_part_2(gimme_input::INPUT_RULES, gimme_input::INPUT_ORDER));
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    println!("WOW THAT LOOK {:?}", end.abs_diff(start));
+pub struct Day5;
 
+impl Solution for Day5 {
+    type Parsed = SafetyManual;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, manual: &Self::Parsed) -> String {
+        solve_part_1(manual).to_string()
+    }
+
+    fn part2(&self, manual: &Self::Parsed) -> String {
+        solve_part_2(manual).to_string()
+    }
+}
+
+pub struct SafetyManual {
+    // page -> every page that has to come after it
+    rules: HashMap<u32, HashSet<u32>>,
+    orders: Vec<Vec<u32>>,
+}
+
+fn parse_input(input: &str) -> SafetyManual {
+    let (rules, order) = split_input(input);
+
+    SafetyManual {
+        rules: parse_rules(rules),
+        orders: parse_order(order),
+    }
 }
 
-fn solve_part_1(rules: &str, order: &str) -> u32 {
-    let parsed_rules = parse_rules(rules);
-    let parsed_orders = parse_order(order);
+// rules come first, then a blank line, then the page orders
+fn split_input(input: &str) -> (&str, &str) {
+    let [rules, order] = sections(input)[..] else {
+        panic!("Expected page ordering rules and page orders separated by a blank line");
+    };
 
-    parsed_orders.iter()
-        .filter(|order| is_order_valid(order, &parsed_rules))
+    (rules, order)
+}
+
+fn solve_part_1(manual: &SafetyManual) -> u32 {
+    manual.orders.iter()
+        .filter(|order| is_order_valid(order, &manual.rules))
         .map(|order| order.get(order.len() / 2).unwrap())
         .sum()
 }
@@ -47,7 +74,7 @@ fn parse_rules(rules: &str) -> HashMap<u32, HashSet<u32>> {
         .for_each(|(key, value)| {
             // so glad Rust has this, love using something similar in Java
             parsed_rules.entry(key)
-                .or_insert_with(|| HashSet::new())
+                .or_default()
                 .insert(value);
         });
 
@@ -64,7 +91,7 @@ fn parse_order(order: &str) -> Vec<Vec<u32>> {
         .collect()
 }
 
-fn is_order_valid(order: &Vec<u32>, rules: &HashMap<u32, HashSet<u32>>) -> bool {
+fn is_order_valid(order: &[u32], rules: &HashMap<u32, HashSet<u32>>) -> bool {
     // sliding window where we look at the value just left to the window, while shrinking the right side
     for i in 0..order.len() - 1 {
         let current = &order[i];
@@ -81,17 +108,14 @@ fn is_order_valid(order: &Vec<u32>, rules: &HashMap<u32, HashSet<u32>>) -> bool
     true
 }
 
-fn solve_part_2(rules: &str, order: &str) -> u32 {
-    let parsed_rules = parse_rules(rules);
-    let parsed_orders = parse_order(order);
-
-    let bad_orders: Vec<Vec<u32>> = parsed_orders.iter()
-        .filter(|order| !is_order_valid(order, &parsed_rules))
+fn solve_part_2(manual: &SafetyManual) -> u32 {
+    let bad_orders: Vec<Vec<u32>> = manual.orders.iter()
+        .filter(|order| !is_order_valid(order, &manual.rules))
         .cloned()
         .collect();
 
     let meow: Vec<Vec<u32>> = bad_orders.iter()
-        .map(|order| recursive_bullshit(order, &parsed_rules))
+        .map(|order| recursive_bullshit(order, &manual.rules))
         .collect();
 
     meow.iter()
@@ -140,7 +164,7 @@ fn solve_part_2(rules: &str, order: &str) -> u32 {
 ///
 /// Call 1
 ///  vec = [61,29,13]
-fn recursive_bullshit(order: &Vec<u32>, rules: &HashMap<u32, HashSet<u32>>) -> Vec<u32> {
+fn recursive_bullshit(order: &[u32], rules: &HashMap<u32, HashSet<u32>>) -> Vec<u32> {
     let mut vec: Vec<u32> = Vec::new();
 
     // Base case
@@ -151,23 +175,71 @@ fn recursive_bullshit(order: &Vec<u32>, rules: &HashMap<u32, HashSet<u32>>) -> V
     let current = &order[0];
     let window = &order[1..];
 
-    let mut is_current_fine = true;
     for window_num in window {
         if let Some(rule) = rules.get(window_num) {
             if rule.contains(current) {
-                is_current_fine = false;
                 let mut updated_order: Vec<u32> = Vec::from(window);
-                updated_order.push(current.clone());
+                updated_order.push(*current);
 
                 return recursive_bullshit(&updated_order, rules);
             }
         }
     }
 
-    if is_current_fine {
-        vec.push(current.clone());
-        vec.extend(recursive_bullshit(&Vec::from(window), rules));
-    }
+    // current is in the right spot, keep it and sort out the rest
+    vec.push(*current);
+    vec.extend(recursive_bullshit(window, rules));
 
     vec
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 143);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 123);
+    }
+
+    #[test]
+    fn crlf_input() {
+        assert_eq!(solve_part_1(&parse_input(&EXAMPLE.replace('\n', "\r\n"))), 143);
+    }
+}
@@ -0,0 +1,211 @@
+use std::collections::HashSet;
+
+use aoc_core::Solution;
+use aoc_grid::{Direction, Grid, Pos};
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed = LabMaze;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_maze(input)
+    }
+
+    fn part1(&self, maze: &Self::Parsed) -> String {
+        solve_part_1(maze).to_string()
+    }
+
+    fn part2(&self, maze: &Self::Parsed) -> String {
+        solve_part_2(maze).to_string()
+    }
+}
+
+fn solve_part_1(maze: &LabMaze) -> usize {
+    let mut maze = maze.clone();
+
+    walk_maze(&mut maze);
+
+    maze.visited.len()
+}
+
+fn walk_maze(maze: &mut LabMaze) {
+    let mut guard = Guard::new(&maze.guard_start);
+    let mut location = maze.get_location(&guard.position);
+
+    while location != &Location::Void {
+        maze.visit(&guard.position);
+
+        let next_position = guard.get_next_position();
+        let next_location = maze.get_location(&next_position);
+
+        guard.update_direction(next_location);
+        guard.go();
+        location = maze.get_location(&guard.position);
+    }
+}
+
+fn parse_maze(input: &str) -> LabMaze {
+    let mut grid = Grid::parse(input);
+    let guard_start = grid.take_marker(&'^', '.')
+        .expect("Expected the guard '^' somewhere in the lab");
+
+    LabMaze {
+        maze: grid.map(|&location| match location {
+            '#' => Location::Obstruction,
+            _ => Location::Clear,
+        }),
+        guard_start,
+        visited: HashSet::new()
+    }
+}
+
+fn solve_part_2(maze: &LabMaze) -> usize {
+    let mut maze = maze.clone();
+
+    walk_maze(&mut maze);
+    let happy_path = maze.visited.clone();
+    get_max_obstructive_asshole_factor(&happy_path, &mut maze)
+}
+
+/// A lone guard patrols
+/// winding, endless halls - ages;
+/// Lights flicker, bugs crawl.
+///
+/// Idea is to record the guard's location AND direction. If the guard is in the same location AND direction
+/// we're looping and can add it to our obstructions set
+fn get_max_obstructive_asshole_factor(happy_path: &HashSet<Pos>, maze: &mut LabMaze) -> usize {
+    let mut looping_obstructions = HashSet::<Pos>::new();
+    let mut guard = Guard::new(&maze.guard_start);
+
+
+    for happy_position in happy_path {
+        guard.reset_guard_pos(&maze.guard_start);
+        // add the obstruction to test if it creates a loop
+        maze.add_obstruction(happy_position);
+
+        let mut location = maze.get_location(&guard.position);
+
+        let mut seen_positions: HashSet<(Pos, Direction)> = HashSet::new();
+        while location != &Location::Void {
+            let current_state = (guard.position, guard.direction);
+
+            if seen_positions.contains(&current_state) {
+                looping_obstructions.insert(*happy_position);
+           //     println!("FOUND A LOOP AT HAPPY POSITION {:?}", happy_position);
+                break;
+            }
+            seen_positions.insert(current_state);
+
+            let lookahead_position = guard.get_next_position();
+            let next_location = maze.get_location(&lookahead_position);
+
+            guard.update_direction(next_location);
+            if maze.get_location(&guard.get_next_position()) != &Location::Obstruction {
+                guard.go();
+            }
+
+            location = maze.get_location(&guard.position);
+        }
+
+        // now remove the obstruction so we can test a new obstruction
+        maze.remove_obstruction(happy_position);
+    }
+
+    looping_obstructions.len()
+}
+
+
+#[derive(Clone)]
+pub struct LabMaze {
+    maze: Grid<Location>,
+    guard_start: Pos,
+    visited: HashSet<Pos>
+}
+
+impl LabMaze {
+    // walking off the grid is how the guard leaves
+    fn get_location(&self, position: &Pos) -> &Location {
+        self.maze.get(*position).unwrap_or(&Location::Void)
+    }
+
+    fn visit(&mut self, position: &Pos) -> bool {
+        self.visited.insert(*position)
+    }
+
+    fn add_obstruction(&mut self, obstruction_pos: &Pos) {
+        self.maze.set(*obstruction_pos, Location::Obstruction);
+    }
+
+    fn remove_obstruction(&mut self, obstruction_pos: &Pos) {
+        self.maze.set(*obstruction_pos, Location::Clear);
+    }
+}
+
+struct Guard {
+    position: Pos,
+    direction: Direction
+}
+
+impl Guard {
+    fn new(start_position: &Pos) -> Self {
+        Guard {
+            position: *start_position,
+            direction: Direction::Up
+        }
+    }
+
+    fn update_direction(&mut self, location: &Location) {
+        if location == &Location::Obstruction {
+            self.direction = self.direction.rotate_clockwise();
+        }
+    }
+
+    fn get_next_position(&self) -> Pos {
+        self.position.next(self.direction)
+    }
+
+    fn go(&mut self) {
+        self.position = self.get_next_position();
+    }
+
+    fn reset_guard_pos(&mut self, start_pos: &Pos) {
+        self.position = *start_pos;
+        self.direction = Direction::Up;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Location {
+    Clear,
+    Obstruction,
+    Void
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_maze(EXAMPLE)), 41);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_maze(EXAMPLE)), 6);
+    }
+}
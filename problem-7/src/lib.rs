@@ -1,20 +1,26 @@
-use std::collections::HashSet;
-use std::time::{SystemTime, UNIX_EPOCH};
+use aoc_core::Solution;
 
-mod gimme_input;
+pub struct Day7;
 
-fn main() {
-    //println!("PART 1 {}", solve_part_1(gimme_input::INPUT));
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT));
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+impl Solution for Day7 {
+    type Parsed = Vec<(u64, Vec<u64>)>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, equations: &Self::Parsed) -> String {
+        solve_part_1(equations).to_string()
+    }
+
+    fn part2(&self, equations: &Self::Parsed) -> String {
+        solve_part_2(equations).to_string()
+    }
 }
 
-fn solve_part_1(input: &str) -> u64 {
-    parse_input(input)
-        .into_iter()
-        .filter(|(target, numbers)| recursive_hole_1(*target, &numbers, None))
+fn solve_part_1(equations: &[(u64, Vec<u64>)]) -> u64 {
+    equations.iter()
+        .filter(|(target, numbers)| recursive_hole_1(*target, numbers, None))
         .map(|(target, _)| target)
         .sum()
 }
@@ -58,10 +64,9 @@ fn recursive_hole_1(target: u64, numbers: &[u64], running_total: Option<u64>) ->
     }
 }
 
-fn solve_part_2(input: &str) -> u64 {
-    parse_input(input)
-        .into_iter()
-        .filter(|(target, numbers)| recursive_hole_2(*target, &numbers, None))
+fn solve_part_2(equations: &[(u64, Vec<u64>)]) -> u64 {
+    equations.iter()
+        .filter(|(target, numbers)| recursive_hole_2(*target, numbers, None))
         .map(|(target, _)| target)
         .sum()
 }
@@ -93,3 +98,37 @@ fn recursive_hole_2(target: u64, numbers: &[u64], running_total: Option<u64>) ->
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 3749);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 11387);
+    }
+
+    #[test]
+    fn concat() {
+        assert_eq!(concat_numbers(15, 6), 156);
+        assert_eq!(concat_numbers(12, 345), 12345);
+        assert_eq!(concat_numbers(7, 0), 70);
+        assert_eq!(concat_numbers(10, 10), 1010);
+    }
+}
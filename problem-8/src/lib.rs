@@ -0,0 +1,178 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_core::Solution;
+use aoc_grid::{Grid, Pos};
+
+/// A dulling winter's setting sun brings forth
+/// A wind that bears the desert's sand and dust
+/// From dune to dune, from dusk to dream to dawn,
+/// And fills an engineer's boots with its gifts
+/// And threads its chill through all his shaking bones
+/// As numbness claims his finger, claims his hand.
+///
+/// "What for, what for?" he asks while tuning tones;
+/// Another voice now drifts about his mind.
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed = City;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, city: &Self::Parsed) -> String {
+        solve_part_1(city).to_string()
+    }
+
+    fn part2(&self, city: &Self::Parsed) -> String {
+        solve_part_2(city).to_string()
+    }
+}
+
+fn solve_part_1(city: &City) -> u32 {
+    let mut antinode_locations = HashSet::new();  // Track unique locations
+
+    for structures in city.structures.values() {
+        let antinode_candidates = get_all_antinode_pairs(structures);
+        for antinodes in antinode_candidates {
+            if city.is_valid_coordinate(&antinodes.0) {
+                antinode_locations.insert(antinodes.0);  // Only insert, won't duplicate
+            }
+            if city.is_valid_coordinate(&antinodes.1) {
+                antinode_locations.insert(antinodes.1);  // Only insert, won't duplicate
+            }
+        }
+    }
+
+    antinode_locations.len() as u32
+}
+
+fn parse_input(input: &str) -> City {
+    City::new(Grid::parse(input))
+}
+
+fn get_all_antinode_pairs(antenna_positions: &[Pos]) -> Vec<(Pos, Pos)> {
+    let mut all_antinodes = Vec::new();
+
+    for i in 0..antenna_positions.len() {
+        for j in (i + 1)..antenna_positions.len() {
+            all_antinodes.push(get_antinode_pair(&antenna_positions[i], &antenna_positions[j]));
+        }
+    }
+
+    all_antinodes
+}
+
+fn get_antinode_pair(first_coordinate: &Pos, second_coordinate: &Pos) -> (Pos, Pos) {
+    let node_distance = *first_coordinate - *second_coordinate;
+
+    (*first_coordinate + node_distance, *second_coordinate - node_distance)
+}
+
+fn solve_part_2(city: &City) -> u32 {
+    let mut antinode_locations = HashSet::new();  // Track unique locations
+
+    for structures in city.structures.values() {
+        let antinode_candidates = get_all_antinodes(structures, city);
+        for antinodes in antinode_candidates {
+            for antinode in antinodes {
+                antinode_locations.insert(antinode);
+            }
+        }
+    }
+
+    antinode_locations.len() as u32
+}
+
+fn get_all_antinodes(antenna_positions: &[Pos], city: &City) -> Vec<HashSet<Pos>> {
+    let mut all_antinodes = Vec::new();
+
+    for i in 0..antenna_positions.len() {
+        for j in (i + 1)..antenna_positions.len() {
+            all_antinodes.push(
+                get_antinodes_for_coords(&antenna_positions[i], &antenna_positions[j], city));
+        }
+    }
+
+    all_antinodes
+}
+
+fn get_antinodes_for_coords(first_coordinate: &Pos, second_coordinate: &Pos, city: &City) -> HashSet<Pos> {
+    let mut antinodes = HashSet::new();
+
+    // Calculate the distance vector between nodes
+    let node_distance = *first_coordinate - *second_coordinate;
+
+    let mut current = *first_coordinate;
+    while city.is_valid_coordinate(&current) {
+        antinodes.insert(current);
+        current = current + node_distance;
+    }
+
+    let mut current = *second_coordinate;
+    while city.is_valid_coordinate(&current) {
+        antinodes.insert(current);
+        current = current - node_distance;
+    }
+
+    antinodes
+}
+
+pub struct City {
+    grid: Grid<char>,
+    structures: HashMap<char, Vec<Pos>>,
+}
+
+impl City {
+    fn new(grid: Grid<char>) -> Self {
+        let mut structures: HashMap<char, Vec<Pos>> = HashMap::new();
+
+        for (position, &structure) in grid.iter() {
+            if structure != '.' {
+                structures.entry(structure)
+                    .or_default()
+                    .push(position);
+            }
+        }
+
+        City {
+            grid,
+            structures,
+        }
+    }
+
+    fn is_valid_coordinate(&self, coordinate: &Pos) -> bool {
+        self.grid.in_bounds(*coordinate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 14);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 34);
+    }
+}
@@ -1,18 +1,25 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use aoc_core::Solution;
 
-mod gimme_input;
+pub struct Day9;
 
-fn main() {
-    let start = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-   // println!("PART 1 {:?}", solve_part_1(gimme_input::INPUT));
-    println!("PART 2 {}", solve_part_2(gimme_input::INPUT));
-    let end = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
-    println!("WOW THAT TOOK {:?}", end.abs_diff(start));
+impl Solution for Day9 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, blocks: &Self::Parsed) -> String {
+        solve_part_1(blocks).to_string()
+    }
+
+    fn part2(&self, blocks: &Self::Parsed) -> String {
+        solve_part_2(blocks).to_string()
+    }
 }
 
-fn solve_part_1(input: &str) -> u64 {
-    let parsed_input = parse_input(input);
-    let updated_system = fill_free_space(&parsed_input);
+fn solve_part_1(parsed_input: &[String]) -> u64 {
+    let updated_system = fill_free_space(parsed_input);
     //println!("PARSED {:?}", parsed_input);
     //println!("UPDATED SYSTEM {:?}", updated_system);
     checksum(&updated_system)
@@ -22,7 +29,8 @@ fn parse_input(input: &str) -> Vec<String> {
     let mut parsed_input: Vec<String> = Vec::new();
 
     let mut id = 0;
-    for (index, character) in input.chars().enumerate() {
+    // input files end with a newline, which isn't a block size
+    for (index, character) in input.trim().chars().enumerate() {
         // even index represents block files
         if index % 2 == 0 {
             let id_str = id.to_string();
@@ -45,8 +53,8 @@ fn parse_input(input: &str) -> Vec<String> {
     parsed_input
 }
 
-fn fill_free_space(file_system: &Vec<String>) -> Vec<String> {
-    let mut filled_space: Vec<String> = file_system.clone();
+fn fill_free_space(file_system: &[String]) -> Vec<String> {
+    let mut filled_space: Vec<String> = file_system.to_vec();
 
     let mut last_right = file_system.len() - 1;
     let mut left = 0;
@@ -73,7 +81,7 @@ fn fill_free_space(file_system: &Vec<String>) -> Vec<String> {
     filled_space
 }
 
-fn checksum(file_system: &Vec<String>) -> u64 {
+fn checksum(file_system: &[String]) -> u64 {
     let mut checksum: u64 = 0;
     for (index, value) in file_system.iter().enumerate() {
         if let Ok(number) = value.parse::<u64>() {
@@ -85,17 +93,16 @@ fn checksum(file_system: &Vec<String>) -> u64 {
     checksum
 }
 
-fn solve_part_2(input: &str) -> u64 {
-    let file_system = parse_input(input);
+fn solve_part_2(file_system: &[String]) -> u64 {
     //  println!("SYSTEM {:?}", file_system);
-    let mut free_space = gimme_free_space_chunks(&file_system);
+    let mut free_space = gimme_free_space_chunks(file_system);
     //  println!("FREE SPACE CHUNKS{:?}", free_space);
 
     // get the block chunks
-    let block_chunks = gimme_block_chunks(&file_system);
+    let block_chunks = gimme_block_chunks(file_system);
     //   println!("BLOCK CHUNKS {:?}", block_chunks);
 
-    let updated_space = fill_free_space_chunks(&file_system, &mut free_space, &block_chunks);
+    let updated_space = fill_free_space_chunks(file_system, &mut free_space, &block_chunks);
     //   println!("UPDATED SPACE {:?}", updated_space);
 
     checksum(&updated_space)
@@ -103,7 +110,7 @@ fn solve_part_2(input: &str) -> u64 {
 
 /// left value is index of space
 /// right value is amount of free space
-fn gimme_free_space_chunks(file_system: &Vec<String>) -> Vec<(usize, usize)> {
+fn gimme_free_space_chunks(file_system: &[String]) -> Vec<(usize, usize)> {
     let mut free_space_chunks = Vec::<(usize, usize)>::new();
 
     let mut i = 0;
@@ -129,7 +136,7 @@ fn gimme_free_space_chunks(file_system: &Vec<String>) -> Vec<(usize, usize)> {
 /// left value is index of the block
 /// middle value is length of the block
 /// right value is the string representation of that block
-fn gimme_block_chunks(file_system: &Vec<String>) -> Vec<(usize, usize, String)> {
+fn gimme_block_chunks(file_system: &[String]) -> Vec<(usize, usize, String)> {
     let mut block_chunks = Vec::new();
     let free_space = ".";
 
@@ -163,12 +170,12 @@ fn gimme_block_chunks(file_system: &Vec<String>) -> Vec<(usize, usize, String)>
 /// 1. loop over the block chunks
 /// 2. loop over free space and find a free space that can accommodate the chunk
 ///     1. If we find a free space, we can start writing free space '.'s to the block_chunk given its index and free space
-///         and write the block chunk value using the free space index and available memory
+///        and write the block chunk value using the free space index and available memory
 ///     2. Then we just need to resort our free space to ensure we are always filling in the space left to right and in memory order
-fn fill_free_space_chunks(file_system: &Vec<String>,
-                          free_space_chunks: &mut Vec<(usize, usize)>,
+fn fill_free_space_chunks(file_system: &[String],
+                          free_space_chunks: &mut [(usize, usize)],
                           block_chunks: &Vec<(usize, usize, String)>) -> Vec<String> {
-    let mut filled_space: Vec<String> = file_system.clone();
+    let mut filled_space: Vec<String> = file_system.to_vec();
 
     for block_chunk in block_chunks {
         let mut is_space_modified = false;
@@ -182,16 +189,12 @@ fn fill_free_space_chunks(file_system: &Vec<String>,
                 // replace right side with dots
                 let start_dot = block_chunk.0 - block_chunk.1 + 1;
                 let end_dot = block_chunk.0 + 1;
-                for i in start_dot..end_dot {
-                    filled_space[i] = ".".to_string();
-                }
+                filled_space[start_dot..end_dot].fill(".".to_string());
 
                 // replace left side with block value
                 let start_write = free_space_chunk.0;
                 let end_write = free_space_chunk.0 + block_chunk.1;
-                for i in start_write..end_write {
-                    filled_space[i] = block_chunk.2.clone();
-                }
+                filled_space[start_write..end_write].fill(block_chunk.2.clone());
 
                 free_space_chunk.1 -= block_chunk.1;
                 free_space_chunk.0 += block_chunk.1;
@@ -222,3 +225,26 @@ fn insertion_sort_pairs(arr: &mut [(usize, usize)]) {
         arr[j] = current;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2333133121414131402\n";
+
+    #[test]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 1928);
+    }
+
+    #[test]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 2858);
+    }
+
+    // ids past 9 take up a single block, not one per digit
+    #[test]
+    fn multi_digit_ids() {
+        assert_eq!(solve_part_1(&parse_input("1010101010101010101010")), 385);
+    }
+}
@@ -0,0 +1,55 @@
+// https://adventofcode.com/2024/day/__DAY__
+
+use aoc_core::Solution;
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Self::Parsed {
+        parse_input(input)
+    }
+
+    fn part1(&self, lines: &Self::Parsed) -> String {
+        solve_part_1(lines).to_string()
+    }
+
+    fn part2(&self, lines: &Self::Parsed) -> String {
+        solve_part_2(lines).to_string()
+    }
+}
+
+fn solve_part_1(_lines: &[String]) -> u64 {
+    todo!()
+}
+
+fn parse_input(input: &str) -> Vec<String> {
+    input.lines()
+        .map(String::from)
+        .collect()
+}
+
+fn solve_part_2(_lines: &[String]) -> u64 {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "\
+";
+
+    #[test]
+    #[ignore = "paste in the example and its answer from the puzzle"]
+    fn part_1_example() {
+        assert_eq!(solve_part_1(&parse_input(EXAMPLE)), 0);
+    }
+
+    #[test]
+    #[ignore = "paste in the example and its answer from the puzzle"]
+    fn part_2_example() {
+        assert_eq!(solve_part_2(&parse_input(EXAMPLE)), 0);
+    }
+}